@@ -0,0 +1,232 @@
+//! Aho-Corasick style automaton compiled from a [`Trie`], for streaming
+//! multi-match tokenization of a long syllable stream without restarting
+//! from the root on every mismatch.
+//!
+//! [`Trie::walk`] only steps one syllable forward and returns `None` on a
+//! mismatch, so a caller scanning for every dictionary word in a stream
+//! has to restart from the root and re-scan. [`Automaton`] adds a failure
+//! link to every node - the state reached by falling back to the longest
+//! proper suffix of the current path that is still a trie prefix - so
+//! [`StreamMatcher::feed`] can always make progress one syllable at a
+//! time and still report every dictionary word ending at the current
+//! position, including overlapping matches, in amortized linear time.
+
+use std::collections::{HashMap, VecDeque};
+
+use crate::trie::{Trie, TrieNode, WordData};
+
+const ROOT: u32 = 0;
+
+/// A precomputed Aho-Corasick automaton over a [`Trie`]. Build one with
+/// [`Trie::build_automaton`] and drive it with a [`StreamMatcher`].
+#[derive(Debug, Clone, Default)]
+pub struct Automaton {
+    /// `children[state]` is the same child table the source `TrieNode` had.
+    children: Vec<HashMap<String, u32>>,
+    /// `fail[state]` is the longest proper suffix of `state`'s path that is
+    /// also reachable from the root; `fail[ROOT] == ROOT`.
+    fail: Vec<u32>,
+    /// Whether `state` is itself a dictionary word ending.
+    is_leaf: Vec<bool>,
+    /// The dictionary data for `state`, if it is a leaf.
+    data: Vec<Option<WordData>>,
+    /// Number of syllables on the path from the root to `state`, i.e. the
+    /// length (in syllables) of the word ending at a leaf state.
+    depth: Vec<usize>,
+}
+
+impl Automaton {
+    /// Compile an Aho-Corasick automaton from a [`Trie`].
+    pub fn from_trie(trie: &Trie) -> Self {
+        let mut automaton = Automaton {
+            children: vec![HashMap::new()],
+            fail: vec![ROOT],
+            is_leaf: vec![trie.root().is_leaf],
+            data: vec![trie.root().data.clone()],
+            depth: vec![0],
+        };
+
+        let mut queue: VecDeque<(u32, &TrieNode)> = VecDeque::new();
+
+        for (syl, child) in &trie.root().children {
+            let id = automaton.push_node(child, 1);
+            automaton.children[ROOT as usize].insert(syl.clone(), id);
+            automaton.fail[id as usize] = ROOT;
+            queue.push_back((id, child));
+        }
+
+        while let Some((id, node)) = queue.pop_front() {
+            for (syl, child) in &node.children {
+                let child_id = automaton.push_node(child, automaton.depth[id as usize] + 1);
+                automaton.children[id as usize].insert(syl.clone(), child_id);
+
+                let mut f = automaton.fail[id as usize];
+                while f != ROOT && !automaton.children[f as usize].contains_key(syl) {
+                    f = automaton.fail[f as usize];
+                }
+                // `f` was processed earlier in this BFS, so it has strictly
+                // smaller depth than `child_id` and can't transition back to it.
+                automaton.fail[child_id as usize] =
+                    automaton.children[f as usize].get(syl).copied().unwrap_or(ROOT);
+
+                queue.push_back((child_id, child));
+            }
+        }
+
+        automaton
+    }
+
+    fn push_node(&mut self, node: &TrieNode, depth: usize) -> u32 {
+        let id = self.children.len() as u32;
+        self.children.push(HashMap::new());
+        self.fail.push(ROOT);
+        self.is_leaf.push(node.is_leaf);
+        self.data.push(node.data.clone());
+        self.depth.push(depth);
+        id
+    }
+
+    /// Follow a real child transition if one exists, otherwise fall back
+    /// through failure links until one does (or the root is reached).
+    fn goto(&self, state: u32, syl: &str) -> u32 {
+        let mut s = state;
+        loop {
+            if let Some(&next) = self.children[s as usize].get(syl) {
+                return next;
+            }
+            if s == ROOT {
+                return ROOT;
+            }
+            s = self.fail[s as usize];
+        }
+    }
+
+    /// Every leaf state along `state`'s failure chain (itself first),
+    /// i.e. every dictionary word ending at `state` - longest first, since
+    /// each failure link points to a strictly shorter suffix.
+    fn matches_at(&self, state: u32) -> Vec<u32> {
+        let mut matches = Vec::new();
+        let mut s = state;
+        loop {
+            if self.is_leaf[s as usize] {
+                matches.push(s);
+            }
+            if s == ROOT {
+                break;
+            }
+            s = self.fail[s as usize];
+        }
+        matches
+    }
+}
+
+/// A dictionary word found ending at the syllable just fed to a
+/// [`StreamMatcher`].
+#[derive(Debug, Clone, Copy)]
+pub struct StreamMatch<'a> {
+    /// Length of the matched word, in syllables.
+    pub len: usize,
+    /// Position (in syllables fed so far) the match ends at.
+    pub end: usize,
+    /// The matched word's dictionary data.
+    pub data: Option<&'a WordData>,
+}
+
+/// Drives an [`Automaton`] over a syllable stream fed one syllable at a
+/// time, reporting every dictionary word ending at each position without
+/// ever restarting from the root.
+pub struct StreamMatcher<'a> {
+    automaton: &'a Automaton,
+    state: u32,
+    pos: usize,
+}
+
+impl<'a> StreamMatcher<'a> {
+    /// Create a matcher positioned at the automaton's root.
+    pub fn new(automaton: &'a Automaton) -> Self {
+        StreamMatcher { automaton, state: ROOT, pos: 0 }
+    }
+
+    /// Feed one syllable, advancing the automaton by a single transition
+    /// (a real child or, on mismatch, a failure-link fallback), and return
+    /// every dictionary word ending exactly at this syllable, longest
+    /// first.
+    pub fn feed(&mut self, syl: &str) -> Vec<StreamMatch<'a>> {
+        self.state = self.automaton.goto(self.state, syl);
+        self.pos += 1;
+
+        self.automaton
+            .matches_at(self.state)
+            .into_iter()
+            .map(|s| StreamMatch {
+                len: self.automaton.depth[s as usize],
+                end: self.pos,
+                data: self.automaton.data[s as usize].as_ref(),
+            })
+            .collect()
+    }
+
+    /// Reset the matcher back to the automaton's root, e.g. between
+    /// independent streams sharing one compiled [`Automaton`].
+    pub fn reset(&mut self) {
+        self.state = ROOT;
+        self.pos = 0;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::trie::TrieBuilder;
+
+    fn make_test_trie() -> Trie {
+        let tsv = "བཀྲ་ཤིས\tNOUN\t\t\t1000\nཤིས\tNOUN\t\t\t10\nབཀྲ\tNOUN\t\t\t5";
+        let mut builder = TrieBuilder::new();
+        builder.load_tsv(tsv);
+        builder.build()
+    }
+
+    #[test]
+    fn test_stream_matcher_finds_overlapping_matches() {
+        let trie = make_test_trie();
+        let automaton = trie.build_automaton();
+        let mut matcher = StreamMatcher::new(&automaton);
+
+        assert!(matcher.feed("བཀྲ").iter().any(|m| m.len == 1));
+
+        // "ཤིས" alone is a word, and "བཀྲ་ཤིས" (the last two syllables fed)
+        // is also a word - both should be reported ending here, without
+        // the matcher ever having restarted from the root.
+        let matches = matcher.feed("ཤིས");
+        assert_eq!(matches.len(), 2);
+        assert_eq!(matches[0].len, 2); // longest match first
+        assert_eq!(matches[1].len, 1);
+    }
+
+    #[test]
+    fn test_stream_matcher_recovers_without_restarting_on_mismatch() {
+        let trie = make_test_trie();
+        let automaton = trie.build_automaton();
+        let mut matcher = StreamMatcher::new(&automaton);
+
+        matcher.feed("བཀྲ");
+        // "ང" continues no known path from "བཀྲ", so the automaton must
+        // fail back (here, all the way to the root) instead of getting
+        // stuck - and still recognize "བཀྲ" again right after.
+        assert!(matcher.feed("ང").is_empty());
+        assert!(matcher.feed("བཀྲ").iter().any(|m| m.len == 1));
+    }
+
+    #[test]
+    fn test_stream_matcher_reset() {
+        let trie = make_test_trie();
+        let automaton = trie.build_automaton();
+        let mut matcher = StreamMatcher::new(&automaton);
+
+        matcher.feed("བཀྲ");
+        matcher.reset();
+        let matches = matcher.feed("ཤིས");
+        assert_eq!(matches.len(), 1);
+        assert_eq!(matches[0].end, 1);
+    }
+}
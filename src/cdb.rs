@@ -0,0 +1,301 @@
+//! Precompiled constant-database (cdb) dictionary format for zero-parse
+//! startup.
+//!
+//! Like [`crate::double_array::DoubleArrayTrie`] and [`crate::dawg::Dawg`],
+//! a [`CdbTrie`] is an immutable, flat representation compiled from a
+//! node-per-entry [`Trie`] for a large dictionary's load-time win. Where
+//! those backends are `bincode`-serialized blobs read and deserialized in
+//! full before any lookup can happen, a cdb (in the style of djb's constant
+//! database) is a random-access on-disk hash table: [`CdbTrie::open_mmap`]
+//! only needs to read the 256-entry header, and every lookup afterwards
+//! touches just the handful of pages its hash probe needs.
+//!
+//! Every distinct path from the trie's root (not just leaves) is stored as
+//! its own record, keyed by its syllables joined with the tsek `་` - the
+//! same form [`Trie::walk`] is driven with - so [`CdbTrie::walk`] can
+//! confirm an intermediate prefix exists on the way to a longer match
+//! instead of only being able to look up whole words.
+
+use std::path::Path;
+
+use crate::double_array::DoubleArrayError;
+use crate::trie::{Trie, TrieNode, WordData};
+
+/// Number of hash tables a key can land in - the low 8 bits of its hash
+/// pick one of these, following djb's cdb.
+const NUM_TABLES: usize = 256;
+/// Byte size of one header entry: a `(table_position, slot_count)` pair,
+/// each stored as a `u32`.
+const HEADER_ENTRY_LEN: usize = 8;
+/// Byte size of the header: one entry per hash table.
+const HEADER_LEN: usize = NUM_TABLES * HEADER_ENTRY_LEN;
+/// Byte size of one hash-table slot: a `(hash, record_position)` pair.
+const SLOT_LEN: usize = 8;
+
+/// The value stored in a cdb record for one trie path: whether it's a
+/// complete word (vs. just a prefix on the way to one) and, if so, its
+/// [`WordData`].
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+struct CdbRecord {
+    is_leaf: bool,
+    data: Option<WordData>,
+}
+
+/// djb's cdb hash: `h = 5381; for b in key { h = ((h << 5) + h) ^ b }`.
+fn cdb_hash(key: &[u8]) -> u32 {
+    let mut h: u32 = 5381;
+    for &b in key {
+        h = h.wrapping_shl(5).wrapping_add(h) ^ (b as u32);
+    }
+    h
+}
+
+/// Recursively collect every `(path, is_leaf, data)` triple reachable from
+/// `node`, appending each child's tsek-joined path to `prefix`.
+fn collect_paths(node: &TrieNode, prefix: &str, out: &mut Vec<(String, bool, Option<WordData>)>) {
+    for (syl, child) in &node.children {
+        let path = if prefix.is_empty() {
+            syl.clone()
+        } else {
+            format!("{prefix}\u{0F0B}{syl}")
+        };
+
+        out.push((path.clone(), child.is_leaf, child.data.clone()));
+        collect_paths(child, &path, out);
+    }
+}
+
+/// Build the constant-database bytes for every reachable path in `trie`.
+fn build_cdb_bytes(trie: &Trie) -> Result<Vec<u8>, DoubleArrayError> {
+    let mut paths = Vec::new();
+    collect_paths(trie.root(), "", &mut paths);
+
+    let mut buf = vec![0u8; HEADER_LEN];
+    let mut buckets: Vec<Vec<(u32, u32)>> = vec![Vec::new(); NUM_TABLES];
+
+    for (path, is_leaf, data) in paths {
+        let key = path.into_bytes();
+        let value = bincode::serialize(&CdbRecord { is_leaf, data })
+            .map_err(|e| DoubleArrayError::Serialize(e.to_string()))?;
+
+        let record_pos = buf.len() as u32;
+        buf.extend_from_slice(&(key.len() as u32).to_le_bytes());
+        buf.extend_from_slice(&(value.len() as u32).to_le_bytes());
+        buf.extend_from_slice(&key);
+        buf.extend_from_slice(&value);
+
+        let hash = cdb_hash(&key);
+        buckets[(hash & 0xFF) as usize].push((hash, record_pos));
+    }
+
+    let mut header = vec![0u8; HEADER_LEN];
+    for (table, entries) in buckets.into_iter().enumerate() {
+        let nslots = entries.len() * 2;
+        header[table * HEADER_ENTRY_LEN..table * HEADER_ENTRY_LEN + 4]
+            .copy_from_slice(&(buf.len() as u32).to_le_bytes());
+        header[table * HEADER_ENTRY_LEN + 4..table * HEADER_ENTRY_LEN + 8]
+            .copy_from_slice(&(nslots as u32).to_le_bytes());
+
+        if nslots == 0 {
+            continue;
+        }
+
+        let mut slots = vec![(0u32, 0u32); nslots];
+        for (hash, record_pos) in entries {
+            let mut slot = (hash >> 8) as usize % nslots;
+            while slots[slot] != (0, 0) {
+                slot = (slot + 1) % nslots;
+            }
+            slots[slot] = (hash, record_pos);
+        }
+
+        for (hash, record_pos) in slots {
+            buf.extend_from_slice(&hash.to_le_bytes());
+            buf.extend_from_slice(&record_pos.to_le_bytes());
+        }
+    }
+
+    buf[..HEADER_LEN].copy_from_slice(&header);
+    Ok(buf)
+}
+
+/// An immutable, on-disk constant-database dictionary compiled from a
+/// [`Trie`].
+///
+/// Has the same `walk(syl, current) -> Option<State>` lookup shape as
+/// [`Trie::walk`], [`crate::double_array::DoubleArrayTrie::walk`], and
+/// [`crate::dawg::Dawg::walk`], except `State` here is the tsek-joined path
+/// walked so far rather than a node index, since a cdb has no notion of
+/// node adjacency - every lookup re-hashes the full path.
+pub struct CdbTrie {
+    bytes: CdbBytes,
+    header: [(u32, u32); NUM_TABLES],
+}
+
+enum CdbBytes {
+    Mmap(memmap2::Mmap),
+    Owned(Vec<u8>),
+}
+
+impl std::ops::Deref for CdbBytes {
+    type Target = [u8];
+
+    fn deref(&self) -> &[u8] {
+        match self {
+            CdbBytes::Mmap(mmap) => mmap,
+            CdbBytes::Owned(bytes) => bytes,
+        }
+    }
+}
+
+fn read_u32(bytes: &[u8], pos: usize) -> u32 {
+    u32::from_le_bytes(bytes[pos..pos + 4].try_into().expect("4-byte slice"))
+}
+
+fn read_header(bytes: &[u8]) -> [(u32, u32); NUM_TABLES] {
+    let mut header = [(0u32, 0u32); NUM_TABLES];
+    for (table, entry) in header.iter_mut().enumerate() {
+        let off = table * HEADER_ENTRY_LEN;
+        *entry = (read_u32(bytes, off), read_u32(bytes, off + 4));
+    }
+    header
+}
+
+impl CdbTrie {
+    /// Compile `trie` into a cdb and write it to `path`, for fast
+    /// zero-parse loading of large dictionaries via [`CdbTrie::open_mmap`].
+    pub fn compile_to_cdb(trie: &Trie, path: impl AsRef<Path>) -> Result<(), DoubleArrayError> {
+        let bytes = build_cdb_bytes(trie)?;
+        std::fs::write(path, bytes).map_err(|e| DoubleArrayError::Io(e.to_string()))
+    }
+
+    /// Memory-map a cdb previously written by [`CdbTrie::compile_to_cdb`],
+    /// reading only its header eagerly.
+    pub fn open_mmap(path: impl AsRef<Path>) -> Result<Self, DoubleArrayError> {
+        let file = std::fs::File::open(path).map_err(|e| DoubleArrayError::Io(e.to_string()))?;
+        let mmap = unsafe { memmap2::Mmap::map(&file) }.map_err(|e| DoubleArrayError::Io(e.to_string()))?;
+        let header = read_header(&mmap);
+        Ok(CdbTrie { bytes: CdbBytes::Mmap(mmap), header })
+    }
+
+    /// Build a cdb entirely in memory, for tests and callers that don't
+    /// need the `mmap` round-trip.
+    fn from_trie(trie: &Trie) -> Result<Self, DoubleArrayError> {
+        let bytes = build_cdb_bytes(trie)?;
+        let header = read_header(&bytes);
+        Ok(CdbTrie { bytes: CdbBytes::Owned(bytes), header })
+    }
+
+    /// Probe the hash table for `key`, following the linear-probing scheme
+    /// [`build_cdb_bytes`] wrote it with.
+    fn lookup(&self, key: &[u8]) -> Option<CdbRecord> {
+        let hash = cdb_hash(key);
+        let (table_pos, nslots) = self.header[(hash & 0xFF) as usize];
+        if nslots == 0 {
+            return None;
+        }
+        let nslots = nslots as usize;
+
+        let start_slot = (hash >> 8) as usize % nslots;
+        for i in 0..nslots {
+            let slot = (start_slot + i) % nslots;
+            let slot_off = table_pos as usize + slot * SLOT_LEN;
+            let slot_hash = read_u32(&self.bytes, slot_off);
+            let record_pos = read_u32(&self.bytes, slot_off + 4);
+
+            if slot_hash == 0 && record_pos == 0 {
+                return None;
+            }
+
+            if slot_hash == hash {
+                let record_pos = record_pos as usize;
+                let key_len = read_u32(&self.bytes, record_pos) as usize;
+                let data_len = read_u32(&self.bytes, record_pos + 4) as usize;
+                let record_key = &self.bytes[record_pos + 8..record_pos + 8 + key_len];
+
+                if record_key == key {
+                    let data = &self.bytes[record_pos + 8 + key_len..record_pos + 8 + key_len + data_len];
+                    return bincode::deserialize(data).ok();
+                }
+            }
+        }
+
+        None
+    }
+
+    /// Walk the cdb by one syllable, returning the path walked so far if
+    /// the transition is valid. Has the same semantics as [`Trie::walk`].
+    pub fn walk(&self, syl: &str, current: Option<&str>) -> Option<String> {
+        let path = match current {
+            Some(prefix) => format!("{prefix}\u{0F0B}{syl}"),
+            None => syl.to_string(),
+        };
+
+        self.lookup(path.as_bytes()).map(|_| path)
+    }
+
+    /// Whether `state` marks the end of a valid word.
+    pub fn is_match(&self, state: &str) -> bool {
+        self.lookup(state.as_bytes()).map(|r| r.is_leaf).unwrap_or(false)
+    }
+
+    /// The dictionary data attached to `state`, if any.
+    pub fn word_data(&self, state: &str) -> Option<WordData> {
+        self.lookup(state.as_bytes()).and_then(|r| r.data)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::trie::TrieBuilder;
+
+    fn make_test_trie() -> Trie {
+        let tsv = "བཀྲ་ཤིས\tNOUN\t\t\t1000\nབདེ་ལེགས\tNOUN\t\t\t500";
+        let mut builder = TrieBuilder::new();
+        builder.load_tsv(tsv);
+        builder.build()
+    }
+
+    #[test]
+    fn test_cdb_matches_trie_lookups() {
+        let trie = make_test_trie();
+        let cdb = CdbTrie::from_trie(&trie).unwrap();
+
+        let s1 = cdb.walk("བཀྲ", None);
+        assert!(s1.is_some());
+        assert!(!cdb.is_match(s1.as_deref().unwrap()));
+
+        let s2 = cdb.walk("ཤིས", s1.as_deref());
+        assert!(s2.is_some());
+        assert!(cdb.is_match(s2.as_deref().unwrap()));
+        assert_eq!(cdb.word_data(s2.as_deref().unwrap()).and_then(|d| d.freq), Some(1000));
+    }
+
+    #[test]
+    fn test_cdb_rejects_unknown_transition() {
+        let trie = make_test_trie();
+        let cdb = CdbTrie::from_trie(&trie).unwrap();
+
+        assert!(cdb.walk("ཀ", None).is_none());
+    }
+
+    #[test]
+    fn test_cdb_round_trips_through_disk() {
+        let trie = make_test_trie();
+        let dir = std::env::temp_dir().join(format!("botok-rs-cdb-test-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("dictionary.cdb");
+
+        CdbTrie::compile_to_cdb(&trie, &path).unwrap();
+        let cdb = CdbTrie::open_mmap(&path).unwrap();
+
+        let s1 = cdb.walk("བདེ", None);
+        let s2 = cdb.walk("ལེགས", s1.as_deref());
+        assert!(s2.is_some());
+        assert!(cdb.is_match(s2.as_deref().unwrap()));
+        assert_eq!(cdb.word_data(s2.as_deref().unwrap()).and_then(|d| d.freq), Some(500));
+
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+}
@@ -3,34 +3,152 @@
 //! This module segments text into chunks (syllables, punctuation, etc.) that can
 //! then be processed by the tokenizer.
 
+use std::borrow::Cow;
+
 use crate::char_categories::{BoString, CharCategory};
 use crate::token::ChunkType;
 
 /// A chunk of text with its type and position
 #[derive(Debug, Clone)]
-pub struct Chunk {
-    /// The syllable text (cleaned, without tsek) - None for non-syllable chunks
-    pub syl: Option<String>,
+pub struct Chunk<'a> {
+    /// The syllable text (cleaned, without tsek) - None for non-syllable
+    /// chunks. Borrows directly from the source text when the syllable is a
+    /// single contiguous byte range (the common case); falls back to an
+    /// owned string only when characters were dropped while reading it (an
+    /// embedded space).
+    pub syl: Option<Cow<'a, str>>,
     /// The type of this chunk
     pub chunk_type: ChunkType,
     /// Starting byte offset in the original string
     pub start: usize,
     /// Length in bytes
     pub len: usize,
+    /// Conditions noticed while reading this chunk that may be worth
+    /// diagnosing downstream (see [`ChunkFlags`]). Chunking itself never
+    /// errors; it only annotates.
+    pub flags: ChunkFlags,
 }
 
-impl Chunk {
-    /// Create a new chunk
-    pub fn new(syl: Option<String>, chunk_type: ChunkType, start: usize, len: usize) -> Self {
+impl<'a> Chunk<'a> {
+    /// Create a new chunk with no flags set
+    pub fn new(syl: Option<Cow<'a, str>>, chunk_type: ChunkType, start: usize, len: usize) -> Self {
         Chunk {
             syl,
             chunk_type,
             start,
             len,
+            flags: ChunkFlags::NONE,
+        }
+    }
+
+    /// Convert to a `'static` chunk, cloning the syllable text if it still
+    /// borrows from the source, so callers can hold onto it independently
+    /// of the `Chunker`/source text that produced it.
+    pub fn into_owned(self) -> Chunk<'static> {
+        Chunk {
+            syl: self.syl.map(|s| Cow::Owned(s.into_owned())),
+            chunk_type: self.chunk_type,
+            start: self.start,
+            len: self.len,
+            flags: self.flags,
         }
     }
 }
 
+/// Bits recording conditions noticed while reading a [`Chunk`] that the
+/// current algorithm swallows rather than rejecting outright - following
+/// rustc_lexer's practice of never erroring during lexing and instead
+/// annotating the token for a later pass to decide what to do. Only set on
+/// `ChunkType::Text` chunks produced by [`Chunks::read_syllable`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct ChunkFlags(u8);
+
+impl ChunkFlags {
+    /// No flags set.
+    pub const NONE: ChunkFlags = ChunkFlags(0);
+    /// The syllable starts with a sub-joined consonant that has no
+    /// preceding head consonant of its own.
+    pub const SUBJOINED_WITHOUT_HEAD: ChunkFlags = ChunkFlags(1 << 0);
+    /// An in-syllable mark appears with no base character before it.
+    pub const MARK_WITHOUT_BASE: ChunkFlags = ChunkFlags(1 << 1);
+    /// A vowel sign appears before any consonant in the syllable.
+    pub const VOWEL_BEFORE_CONSONANT: ChunkFlags = ChunkFlags(1 << 2);
+    /// The syllable contains a space that split what would otherwise have
+    /// been read as one contiguous run.
+    pub const SPACE_SPLIT: ChunkFlags = ChunkFlags(1 << 3);
+
+    /// Whether every bit set in `other` is also set in `self`.
+    pub fn contains(self, other: ChunkFlags) -> bool {
+        self.0 & other.0 == other.0
+    }
+
+    /// Whether no flags are set.
+    pub fn is_empty(self) -> bool {
+        self.0 == 0
+    }
+
+    fn insert(&mut self, other: ChunkFlags) {
+        self.0 |= other.0;
+    }
+}
+
+impl std::ops::BitOr for ChunkFlags {
+    type Output = ChunkFlags;
+
+    fn bitor(self, rhs: ChunkFlags) -> ChunkFlags {
+        ChunkFlags(self.0 | rhs.0)
+    }
+}
+
+/// The kind of structural problem noticed while validating a chunk, paired
+/// with its byte span in a [`TokenError`].
+///
+/// Unlike [`ChunkFlags`], which silently annotates a chunk for a caller that
+/// already has one to inspect, these are collected into their own list by
+/// [`Chunker::make_chunks_validated`] - borrowing the `ParsedToken { token,
+/// error }` split from rust-analyzer's lexer - so a proofreading/IME caller
+/// can highlight broken spans without walking every chunk's flags itself.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TokenErrorKind {
+    /// A sub-joined consonant with no preceding head consonant of its own
+    /// (see [`ChunkFlags::SUBJOINED_WITHOUT_HEAD`]).
+    DanglingSubCons,
+    /// Two tseks in a row with no syllable content between them.
+    DoubleTsek,
+    /// A vowel sign with no consonant before it in the syllable (see
+    /// [`ChunkFlags::VOWEL_BEFORE_CONSONANT`]).
+    OrphanVowel,
+    /// A syllable mixes Tibetan/Sanskrit characters with characters from
+    /// another script.
+    MixedScriptInSyllable,
+}
+
+/// A structural problem found while validating a chunk (see
+/// [`Chunker::make_chunks_validated`]), with the exact byte range it spans
+/// in the source text.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TokenError {
+    /// What kind of problem this is
+    pub kind: TokenErrorKind,
+    /// Starting byte offset in the original string
+    pub start: usize,
+    /// Length in bytes
+    pub len: usize,
+}
+
+/// Multi-character punctuation sequences that carry more meaning than a
+/// generic `ChunkType::Punct` run - yig-mgo / head marks opening a section
+/// and the marks that close one. Checked longest-first by [`Chunks::read_mark`]
+/// so e.g. `༄༅༅` is recognized whole rather than as a bare `༄` plus leftovers.
+const PUNCT_MARKS: &[(&str, ChunkType)] = &[
+    ("\u{0F04}\u{0F05}\u{0F05}", ChunkType::HeadMark), // ༄༅༅
+    ("\u{0F04}\u{0F05}", ChunkType::HeadMark),         // ༄༅
+    ("\u{0F04}", ChunkType::HeadMark),                 // ༄
+    ("\u{0F0D} \u{0F0D}", ChunkType::ClosingMark),     // tsheg-separated shad: ། །
+    ("\u{0F11}", ChunkType::ClosingMark),              // rin-chen-spungs-shad
+    ("\u{0F0E}", ChunkType::ClosingMark),               // double shad: ༎
+];
+
 /// Chunker for Tibetan text
 pub struct Chunker {
     /// The analyzed string
@@ -50,157 +168,312 @@ impl Chunker {
         &self.bs.string
     }
 
+    /// Chunk the text into syllables, punctuation, etc., one at a time.
+    ///
+    /// Walks the input with a [`Cursor`] instead of materializing
+    /// `Vec<char>`/byte-offset tables up front, so large inputs can be
+    /// streamed through the tokenizer without an eager allocation pass.
+    pub fn chunks(&self) -> Chunks<'_> {
+        Chunks::new(&self.bs)
+    }
+
     /// Chunk the text into syllables, punctuation, etc.
-    pub fn make_chunks(&self) -> Vec<Chunk> {
-        if self.bs.is_empty() {
-            return Vec::new();
-        }
+    pub fn make_chunks(&self) -> Vec<Chunk<'_>> {
+        self.chunks().collect()
+    }
 
-        let mut chunks = Vec::new();
-        let chars: Vec<char> = self.bs.string.chars().collect();
-        let mut byte_positions: Vec<usize> = Vec::with_capacity(chars.len() + 1);
-        
-        // Calculate byte positions for each character
-        let mut pos = 0;
-        for c in &chars {
-            byte_positions.push(pos);
-            pos += c.len_utf8();
-        }
-        byte_positions.push(pos); // End position
+    /// Chunk the text losslessly, one chunk at a time.
+    ///
+    /// Unlike [`Chunker::chunks`], whitespace is never folded into a
+    /// neighboring chunk or dropped: every run of transparent characters is
+    /// emitted as its own `ChunkType::Space` chunk, so every byte of the
+    /// input is attributable to exactly one chunk and the stream can be
+    /// reassembled verbatim.
+    pub fn chunks_lossless(&self) -> Chunks<'_> {
+        Chunks::new_lossless(&self.bs)
+    }
 
-        let mut i = 0;
-        while i < chars.len() {
-            let cat = self.bs.categories[i];
+    /// Chunk the text losslessly. See [`Chunker::chunks_lossless`].
+    ///
+    /// Guarantees `chunks.iter().map(|c| &text[c.start..c.start+c.len]).collect::<String>() == text`.
+    pub fn make_chunks_lossless(&self) -> Vec<Chunk<'_>> {
+        self.chunks_lossless().collect()
+    }
 
-            match cat {
-                // Tibetan text - find the syllable
-                CharCategory::Cons
-                | CharCategory::SubCons
-                | CharCategory::Vow
-                | CharCategory::SkrtCons
-                | CharCategory::SkrtSubCons
-                | CharCategory::SkrtVow
-                | CharCategory::SkrtLongVow
-                | CharCategory::InSylMark
-                | CharCategory::Nfc
-                | CharCategory::NonBoNonSkrt => {
-                    let (chunk, next_i) = self.read_syllable(&chars, &byte_positions, i);
-                    chunks.push(chunk);
-                    i = next_i;
-                }
+    /// Chunk the text, additionally validating Tibetan syllable structure
+    /// and collecting every problem found along the way.
+    ///
+    /// Chunking itself still never fails: every byte of input still ends up
+    /// in some best-effort chunk exactly as [`Chunker::make_chunks`] would
+    /// produce it. The returned [`TokenError`]s let a caller that cares
+    /// (e.g. an IME or proofreading tool) highlight the broken spans
+    /// without inspecting every chunk's [`ChunkFlags`] itself.
+    pub fn make_chunks_validated(&self) -> (Vec<Chunk<'_>>, Vec<TokenError>) {
+        let mut chunks_iter = Chunks::new_validated(&self.bs);
+        let chunks: Vec<Chunk<'_>> = chunks_iter.by_ref().collect();
+        (chunks, chunks_iter.errors)
+    }
+}
 
-                // Tsek - usually attached to previous syllable, but handle standalone
-                CharCategory::Tsek => {
-                    // Standalone tsek (shouldn't happen often)
-                    let start = byte_positions[i];
-                    let len = chars[i].len_utf8();
-                    chunks.push(Chunk::new(None, ChunkType::Punct, start, len));
-                    i += 1;
-                }
+/// A cursor over the not-yet-consumed suffix of the input, used by [`Chunks`]
+/// to walk the text by whole UTF-8 characters. Following the design of
+/// rustc_lexer and proc-macro2's `Cursor`, it holds only the remaining `&str`
+/// and the offsets needed to resume from it, rather than an index into a
+/// pre-materialized `Vec<char>`.
+#[derive(Clone, Copy)]
+struct Cursor<'a> {
+    bs: &'a BoString,
+    /// Text not yet consumed
+    rest: &'a str,
+    /// Byte offset of `rest` within the original string
+    byte_off: usize,
+    /// Index into `bs.categories` of `rest`'s first character
+    char_idx: usize,
+}
 
-                // Punctuation
-                CharCategory::NormalPunct | CharCategory::SpecialPunct => {
-                    let (chunk, next_i) = self.read_punct(&chars, &byte_positions, i);
-                    chunks.push(chunk);
-                    i = next_i;
-                }
+impl<'a> Cursor<'a> {
+    fn new(bs: &'a BoString) -> Self {
+        Cursor {
+            bs,
+            rest: &bs.string,
+            byte_off: 0,
+            char_idx: 0,
+        }
+    }
 
-                // Numbers
-                CharCategory::Numeral => {
-                    let (chunk, next_i) = self.read_numbers(&chars, &byte_positions, i);
-                    chunks.push(chunk);
-                    i = next_i;
-                }
+    /// Whether the remaining input starts with `s`, for read_* routines that
+    /// need to look ahead at multi-character sequences (e.g. recognizing a
+    /// punctuation run) before deciding how far to advance.
+    fn starts_with(&self, s: &str) -> bool {
+        self.rest.starts_with(s)
+    }
 
-                // Symbols
-                CharCategory::Symbol => {
-                    let (chunk, next_i) = self.read_symbols(&chars, &byte_positions, i);
-                    chunks.push(chunk);
-                    i = next_i;
-                }
+    /// The category of the next character, or `None` at end of input
+    fn first_category(&self) -> Option<CharCategory> {
+        self.bs.get_category(self.char_idx)
+    }
 
-                // Transparent (spaces) - attach to previous chunk or create standalone
-                CharCategory::Transparent => {
-                    // For now, skip spaces or attach to previous
-                    if let Some(last) = chunks.last_mut() {
-                        // Extend the previous chunk to include the space
-                        last.len += chars[i].len_utf8();
-                    }
-                    i += 1;
-                }
+    /// Consume and return the next character, advancing the cursor past it
+    fn bump(&mut self) -> Option<char> {
+        let c = self.rest.chars().next()?;
+        self.rest = &self.rest[c.len_utf8()..];
+        self.byte_off += c.len_utf8();
+        self.char_idx += 1;
+        Some(c)
+    }
+}
 
-                // Latin text
-                CharCategory::Latin => {
-                    let (chunk, next_i) = self.read_latin(&chars, &byte_positions, i);
-                    chunks.push(chunk);
-                    i = next_i;
-                }
+/// A lazy, allocation-free iterator over [`Chunk`]s.
+///
+/// Each call to `next()` dispatches on the cursor's current [`CharCategory`]
+/// and advances it by whole UTF-8 characters, without ever materializing an
+/// intermediate `Vec<char>` or `Vec<Chunk>`. A run of transparent
+/// (space-like) characters is held in `pending`/the carry fields rather than
+/// yielded on its own, so it can be folded into the length of the chunk it
+/// borders - matching the original eager implementation's behavior of
+/// attaching spaces to a neighboring token.
+///
+/// In lossless mode (see [`Chunker::chunks_lossless`]), transparent runs are
+/// never folded or dropped: each is emitted as its own `ChunkType::Space`
+/// chunk. `pending` still holds each chunk back one step in every mode; the
+/// folding of transparent runs into it and the carry fields are only
+/// exercised in the default (lossy) mode.
+pub struct Chunks<'a> {
+    cursor: Cursor<'a>,
+    /// Whether to emit whitespace as its own `ChunkType::Space` chunks
+    /// instead of folding it into a neighboring chunk.
+    lossless: bool,
+    /// Whether to validate Tibetan syllable structure and collect the
+    /// problems found into `errors`. Only set by
+    /// [`Chunker::make_chunks_validated`].
+    validate: bool,
+    /// Structural problems found so far, in source order. Only populated
+    /// when `validate` is set.
+    errors: Vec<TokenError>,
+    /// Span of the tsek that ended the most recently produced chunk - either
+    /// the tsek closing a syllable or a lone tsek chunk - so a tsek
+    /// immediately following it can be flagged as `DoubleTsek`. Only
+    /// tracked when `validate` is set.
+    last_tsek: Option<(usize, usize)>,
+    /// The most recently produced chunk, held back one step so that any
+    /// transparent run immediately following it can be folded into its
+    /// `len` before it is yielded. In lossless mode it only holds the chunk
+    /// back; nothing is folded into it.
+    pending: Option<Chunk<'a>>,
+    /// Start of a transparent run seen before any chunk has been produced
+    /// (e.g. leading whitespace), carried forward into the first chunk.
+    /// Unused in lossless mode.
+    carry_start: Option<usize>,
+    /// Byte length of that leading transparent run. Unused in lossless mode.
+    carry_len: usize,
+}
 
-                // CJK text
-                CharCategory::Cjk => {
-                    let (chunk, next_i) = self.read_cjk(&chars, &byte_positions, i);
-                    chunks.push(chunk);
-                    i = next_i;
-                }
+impl<'a> Chunks<'a> {
+    fn new(bs: &'a BoString) -> Self {
+        Chunks {
+            cursor: Cursor::new(bs),
+            lossless: false,
+            validate: false,
+            errors: Vec::new(),
+            last_tsek: None,
+            pending: None,
+            carry_start: None,
+            carry_len: 0,
+        }
+    }
 
-                // Other
-                CharCategory::Other => {
-                    let start = byte_positions[i];
-                    let len = chars[i].len_utf8();
-                    chunks.push(Chunk::new(None, ChunkType::Other, start, len));
-                    i += 1;
-                }
-            }
+    fn new_lossless(bs: &'a BoString) -> Self {
+        Chunks {
+            cursor: Cursor::new(bs),
+            lossless: true,
+            validate: false,
+            errors: Vec::new(),
+            last_tsek: None,
+            pending: None,
+            carry_start: None,
+            carry_len: 0,
         }
+    }
 
-        chunks
+    fn new_validated(bs: &'a BoString) -> Self {
+        Chunks {
+            cursor: Cursor::new(bs),
+            lossless: false,
+            validate: true,
+            errors: Vec::new(),
+            last_tsek: None,
+            pending: None,
+            carry_start: None,
+            carry_len: 0,
+        }
     }
 
-    /// Read a Tibetan syllable starting at position i
-    fn read_syllable(
-        &self,
-        chars: &[char],
-        byte_positions: &[usize],
-        start_i: usize,
-    ) -> (Chunk, usize) {
-        let mut i = start_i;
-        let mut syl_chars: Vec<char> = Vec::new();
+    /// Read a run of transparent (whitespace) characters as its own chunk.
+    fn read_space(&mut self) -> Chunk<'a> {
+        let start = self.cursor.byte_off;
+        while matches!(self.cursor.first_category(), Some(CharCategory::Transparent)) {
+            self.cursor.bump();
+        }
+        Chunk::new(None, ChunkType::Space, start, self.cursor.byte_off - start)
+    }
 
-        // Read until we hit a tsek or non-syllable character
-        while i < chars.len() {
-            let cat = self.bs.categories[i];
+    /// Append a syllable-content character to the text being accumulated by
+    /// [`Chunks::read_syllable`]. The common case is a contiguous byte range
+    /// starting at `start`, so nothing is allocated until a character fails
+    /// to immediately follow `contiguous_end` (which only happens once an
+    /// embedded space has been skipped), at which point the contiguous
+    /// prefix is copied into `owned` and subsequent characters are pushed
+    /// onto it instead.
+    fn push_syllable_char(bs: &'a BoString, start: usize, contiguous_end: &mut usize, owned: &mut Option<String>, before: usize, c: char) {
+        if owned.is_none() && before == *contiguous_end {
+            *contiguous_end += c.len_utf8();
+        } else {
+            owned.get_or_insert_with(|| bs.string[start..*contiguous_end].to_string()).push(c);
+        }
+    }
 
+    /// Read a Tibetan syllable starting at the cursor's current position
+    fn read_syllable(&mut self) -> Chunk<'a> {
+        let bs = self.cursor.bs;
+        let start = self.cursor.byte_off;
+        let mut contiguous_end = start;
+        let mut owned: Option<String> = None;
+        let mut any_char = false;
+        let mut flags = ChunkFlags::NONE;
+        let mut consonant_seen = false;
+        let mut bo_char_seen = false;
+        let mut non_bo_char_seen = false;
+
+        while let Some(cat) = self.cursor.first_category() {
             match cat {
-                // Part of syllable
-                CharCategory::Cons
-                | CharCategory::SubCons
-                | CharCategory::Vow
-                | CharCategory::SkrtCons
-                | CharCategory::SkrtSubCons
-                | CharCategory::SkrtVow
-                | CharCategory::SkrtLongVow
-                | CharCategory::InSylMark
-                | CharCategory::Nfc
-                | CharCategory::NonBoNonSkrt => {
-                    syl_chars.push(chars[i]);
-                    i += 1;
+                // Head consonants establish a base for anything joined after them
+                CharCategory::Cons | CharCategory::SkrtCons => {
+                    consonant_seen = true;
+                    bo_char_seen = true;
+                    any_char = true;
+                    let before = self.cursor.byte_off;
+                    let c = self.cursor.bump().expect("category implies a char");
+                    Self::push_syllable_char(bs, start, &mut contiguous_end, &mut owned, before, c);
+                }
+
+                // A sub-joined consonant with no preceding head consonant is
+                // unusual: it has nothing to attach under.
+                CharCategory::SubCons | CharCategory::SkrtSubCons => {
+                    if !consonant_seen {
+                        flags.insert(ChunkFlags::SUBJOINED_WITHOUT_HEAD);
+                    }
+                    bo_char_seen = true;
+                    any_char = true;
+                    let before = self.cursor.byte_off;
+                    let c = self.cursor.bump().expect("category implies a char");
+                    Self::push_syllable_char(bs, start, &mut contiguous_end, &mut owned, before, c);
+                }
+
+                // A vowel sign before any consonant has no base to attach to.
+                CharCategory::Vow | CharCategory::SkrtVow | CharCategory::SkrtLongVow => {
+                    if !consonant_seen {
+                        flags.insert(ChunkFlags::VOWEL_BEFORE_CONSONANT);
+                    }
+                    bo_char_seen = true;
+                    any_char = true;
+                    let before = self.cursor.byte_off;
+                    let c = self.cursor.bump().expect("category implies a char");
+                    Self::push_syllable_char(bs, start, &mut contiguous_end, &mut owned, before, c);
+                }
+
+                // An in-syllable mark with nothing read yet this syllable is stray.
+                CharCategory::InSylMark => {
+                    if !any_char {
+                        flags.insert(ChunkFlags::MARK_WITHOUT_BASE);
+                    }
+                    bo_char_seen = true;
+                    any_char = true;
+                    let before = self.cursor.byte_off;
+                    let c = self.cursor.bump().expect("category implies a char");
+                    Self::push_syllable_char(bs, start, &mut contiguous_end, &mut owned, before, c);
+                }
+
+                // Otherwise ordinary syllable content
+                CharCategory::Nfc | CharCategory::NonBoNonSkrt => {
+                    if cat == CharCategory::NonBoNonSkrt {
+                        non_bo_char_seen = true;
+                    } else {
+                        bo_char_seen = true;
+                    }
+                    any_char = true;
+                    let before = self.cursor.byte_off;
+                    let c = self.cursor.bump().expect("category implies a char");
+                    Self::push_syllable_char(bs, start, &mut contiguous_end, &mut owned, before, c);
                 }
 
                 // Tsek ends the syllable (include it in the chunk but not the syl)
                 CharCategory::Tsek => {
-                    i += 1; // Include tsek in chunk length
+                    let tsek_start = self.cursor.byte_off;
+                    self.cursor.bump();
+                    if self.validate {
+                        self.last_tsek = Some((tsek_start, self.cursor.byte_off - tsek_start));
+                    }
                     break;
                 }
 
                 // Transparent (space) within syllable - include and continue
+                // if more syllable content follows, otherwise stop. In
+                // lossless mode, spaces are never absorbed: stop immediately
+                // so the space can be emitted as its own chunk.
                 CharCategory::Transparent => {
-                    i += 1;
-                    // Check if there's more syllable content after the space
-                    if i < chars.len() && self.bs.categories[i].is_syllable_part() {
-                        continue;
-                    } else {
+                    if self.lossless {
+                        break;
+                    }
+                    self.cursor.bump();
+                    let continues = self
+                        .cursor
+                        .first_category()
+                        .map(|c| c.is_syllable_part())
+                        .unwrap_or(false);
+                    if !continues {
                         break;
                     }
+                    flags.insert(ChunkFlags::SPACE_SPLIT);
                 }
 
                 // Anything else ends the syllable
@@ -208,139 +481,185 @@ impl Chunker {
             }
         }
 
-        let start = byte_positions[start_i];
-        let end = byte_positions[i];
-        let len = end - start;
-
-        let syl = if syl_chars.is_empty() {
+        let len = self.cursor.byte_off - start;
+        let syl = if !any_char {
             None
+        } else if let Some(owned) = owned {
+            Some(Cow::Owned(owned))
         } else {
-            Some(syl_chars.into_iter().collect())
+            Some(Cow::Borrowed(&bs.string[start..contiguous_end]))
         };
 
-        (Chunk::new(syl, ChunkType::Text, start, len), i)
-    }
-
-    /// Read punctuation starting at position i
-    fn read_punct(
-        &self,
-        chars: &[char],
-        byte_positions: &[usize],
-        start_i: usize,
-    ) -> (Chunk, usize) {
-        let mut i = start_i;
+        let mut chunk = Chunk::new(syl, ChunkType::Text, start, len);
+        chunk.flags = flags;
 
-        while i < chars.len() {
-            let cat = self.bs.categories[i];
-            match cat {
-                CharCategory::NormalPunct
-                | CharCategory::SpecialPunct
-                | CharCategory::Transparent => {
-                    i += 1;
-                }
-                _ => break,
+        if self.validate {
+            if flags.contains(ChunkFlags::SUBJOINED_WITHOUT_HEAD) {
+                self.errors.push(TokenError { kind: TokenErrorKind::DanglingSubCons, start, len });
+            }
+            if flags.contains(ChunkFlags::VOWEL_BEFORE_CONSONANT) {
+                self.errors.push(TokenError { kind: TokenErrorKind::OrphanVowel, start, len });
+            }
+            if bo_char_seen && non_bo_char_seen {
+                self.errors.push(TokenError { kind: TokenErrorKind::MixedScriptInSyllable, start, len });
             }
         }
 
-        let start = byte_positions[start_i];
-        let end = byte_positions[i];
-        (Chunk::new(None, ChunkType::Punct, start, end - start), i)
+        chunk
     }
 
-    /// Read numbers starting at position i
-    fn read_numbers(
-        &self,
-        chars: &[char],
-        byte_positions: &[usize],
-        start_i: usize,
-    ) -> (Chunk, usize) {
-        let mut i = start_i;
-
-        while i < chars.len() {
-            let cat = self.bs.categories[i];
-            match cat {
-                CharCategory::Numeral | CharCategory::Transparent => {
-                    i += 1;
+    /// Try to match one of the recognized multi-character sequences in
+    /// [`PUNCT_MARKS`] at the cursor's current position, consuming and
+    /// returning it as its own chunk if found.
+    ///
+    /// In lossless mode, patterns that span a space (currently only the
+    /// tsheg-separated shad) are skipped, so the shads on either side of it
+    /// fall back to plain `ChunkType::Punct` chunks rather than the space
+    /// being silently absorbed into a `ClosingMark`.
+    fn read_mark(&mut self) -> Option<Chunk<'a>> {
+        let start = self.cursor.byte_off;
+        for &(pat, chunk_type) in PUNCT_MARKS {
+            // In lossless mode a pattern spanning a transparent character
+            // must not match: that space has to surface as its own `Space`
+            // chunk, same as every other run of whitespace.
+            if self.lossless && pat.contains(' ') {
+                continue;
+            }
+            if self.cursor.starts_with(pat) {
+                for _ in 0..pat.chars().count() {
+                    self.cursor.bump();
                 }
-                _ => break,
+                return Some(Chunk::new(None, chunk_type, start, pat.len()));
             }
         }
-
-        let start = byte_positions[start_i];
-        let end = byte_positions[i];
-        (Chunk::new(None, ChunkType::Num, start, end - start), i)
+        None
     }
 
-    /// Read symbols starting at position i
-    fn read_symbols(
-        &self,
-        chars: &[char],
-        byte_positions: &[usize],
-        start_i: usize,
-    ) -> (Chunk, usize) {
-        let mut i = start_i;
-
-        while i < chars.len() {
-            let cat = self.bs.categories[i];
-            match cat {
-                CharCategory::Symbol | CharCategory::Transparent => {
-                    i += 1;
-                }
-                _ => break,
+    /// Read a run of characters starting at the cursor's current position
+    /// for which `in_run` holds, plus any interleaved transparent
+    /// characters, producing a single chunk of `chunk_type`. In lossless
+    /// mode, transparent characters are never absorbed, so the run stops at
+    /// the first one and it is emitted as its own `Space` chunk instead.
+    fn read_run(&mut self, chunk_type: ChunkType, in_run: fn(CharCategory) -> bool) -> Chunk<'a> {
+        let start = self.cursor.byte_off;
+
+        while let Some(cat) = self.cursor.first_category() {
+            if in_run(cat) || (!self.lossless && cat == CharCategory::Transparent) {
+                self.cursor.bump();
+            } else {
+                break;
             }
         }
 
-        let start = byte_positions[start_i];
-        let end = byte_positions[i];
-        (Chunk::new(None, ChunkType::Sym, start, end - start), i)
+        Chunk::new(None, chunk_type, start, self.cursor.byte_off - start)
     }
 
-    /// Read Latin text starting at position i
-    fn read_latin(
-        &self,
-        chars: &[char],
-        byte_positions: &[usize],
-        start_i: usize,
-    ) -> (Chunk, usize) {
-        let mut i = start_i;
-
-        while i < chars.len() {
-            let cat = self.bs.categories[i];
-            match cat {
-                CharCategory::Latin | CharCategory::Transparent => {
-                    i += 1;
+    /// Produce the next non-transparent chunk. Must only be called when
+    /// `self.cursor.first_category()` is `Some` and not `Transparent`.
+    fn read_next_chunk(&mut self, cat: CharCategory) -> Chunk<'a> {
+        if self.validate && cat != CharCategory::Tsek {
+            self.last_tsek = None;
+        }
+        match cat {
+            CharCategory::Cons
+            | CharCategory::SubCons
+            | CharCategory::Vow
+            | CharCategory::SkrtCons
+            | CharCategory::SkrtSubCons
+            | CharCategory::SkrtVow
+            | CharCategory::SkrtLongVow
+            | CharCategory::InSylMark
+            | CharCategory::Nfc
+            | CharCategory::NonBoNonSkrt => self.read_syllable(),
+
+            CharCategory::Tsek => {
+                let start = self.cursor.byte_off;
+                let len = self.cursor.bump().expect("category implies a char").len_utf8();
+                if self.validate {
+                    if let Some((prev_start, prev_len)) = self.last_tsek {
+                        self.errors.push(TokenError {
+                            kind: TokenErrorKind::DoubleTsek,
+                            start: prev_start,
+                            len: prev_len + len,
+                        });
+                    }
+                    self.last_tsek = Some((start, len));
                 }
-                _ => break,
+                Chunk::new(None, ChunkType::Punct, start, len)
+            }
+
+            CharCategory::NormalPunct | CharCategory::SpecialPunct => self.read_mark().unwrap_or_else(|| {
+                self.read_run(
+                    ChunkType::Punct,
+                    |c| matches!(c, CharCategory::NormalPunct | CharCategory::SpecialPunct),
+                )
+            }),
+
+            CharCategory::Numeral => {
+                self.read_run(ChunkType::Num, |c| matches!(c, CharCategory::Numeral))
+            }
+
+            CharCategory::Symbol => {
+                self.read_run(ChunkType::Sym, |c| matches!(c, CharCategory::Symbol))
+            }
+
+            CharCategory::Latin => {
+                self.read_run(ChunkType::Latin, |c| matches!(c, CharCategory::Latin))
             }
-        }
 
-        let start = byte_positions[start_i];
-        let end = byte_positions[i];
-        (Chunk::new(None, ChunkType::Latin, start, end - start), i)
+            CharCategory::Cjk => self.read_run(ChunkType::Cjk, |c| matches!(c, CharCategory::Cjk)),
+
+            CharCategory::Other => {
+                let start = self.cursor.byte_off;
+                let len = self.cursor.bump().expect("category implies a char").len_utf8();
+                Chunk::new(None, ChunkType::Other, start, len)
+            }
+
+            CharCategory::Transparent => unreachable!("transparent runs are consumed by `next`"),
+        }
     }
+}
 
-    /// Read CJK text starting at position i
-    fn read_cjk(
-        &self,
-        chars: &[char],
-        byte_positions: &[usize],
-        start_i: usize,
-    ) -> (Chunk, usize) {
-        let mut i = start_i;
+impl<'a> Iterator for Chunks<'a> {
+    type Item = Chunk<'a>;
 
-        while i < chars.len() {
-            let cat = self.bs.categories[i];
-            match cat {
-                CharCategory::Cjk | CharCategory::Transparent => {
-                    i += 1;
+    fn next(&mut self) -> Option<Chunk<'a>> {
+        loop {
+            match self.cursor.first_category() {
+                None => return self.pending.take(),
+
+                Some(CharCategory::Transparent) if self.lossless => {
+                    let chunk = self.read_space();
+                    if let Some(prev) = self.pending.replace(chunk) {
+                        return Some(prev);
+                    }
+                }
+
+                Some(CharCategory::Transparent) => {
+                    self.last_tsek = None;
+                    let start = self.cursor.byte_off;
+                    let len = self.cursor.bump().expect("category implies a char").len_utf8();
+                    if let Some(pending) = self.pending.as_mut() {
+                        pending.len += len;
+                    } else {
+                        self.carry_start.get_or_insert(start);
+                        self.carry_len += len;
+                    }
+                }
+
+                Some(cat) => {
+                    let mut chunk = self.read_next_chunk(cat);
+                    if let Some(carry_start) = self.carry_start.take() {
+                        chunk.start = carry_start;
+                        chunk.len += self.carry_len;
+                        self.carry_len = 0;
+                    }
+                    if let Some(prev) = self.pending.replace(chunk) {
+                        return Some(prev);
+                    }
                 }
-                _ => break,
             }
         }
-
-        let start = byte_positions[start_i];
-        let end = byte_positions[i];
-        (Chunk::new(None, ChunkType::Cjk, start, end - start), i)
     }
 }
 
@@ -354,9 +673,9 @@ mod tests {
         let chunks = chunker.make_chunks();
 
         assert_eq!(chunks.len(), 2);
-        assert_eq!(chunks[0].syl, Some("བཀྲ".to_string()));
+        assert_eq!(chunks[0].syl.as_deref(), Some("བཀྲ"));
         assert_eq!(chunks[0].chunk_type, ChunkType::Text);
-        assert_eq!(chunks[1].syl, Some("ཤིས".to_string()));
+        assert_eq!(chunks[1].syl.as_deref(), Some("ཤིས"));
     }
 
     #[test]
@@ -365,8 +684,8 @@ mod tests {
         let chunks = chunker.make_chunks();
 
         assert_eq!(chunks.len(), 3);
-        assert_eq!(chunks[0].syl, Some("བཀྲ".to_string()));
-        assert_eq!(chunks[1].syl, Some("ཤིས".to_string()));
+        assert_eq!(chunks[0].syl.as_deref(), Some("བཀྲ"));
+        assert_eq!(chunks[1].syl.as_deref(), Some("ཤིས"));
         assert_eq!(chunks[2].chunk_type, ChunkType::Punct);
     }
 
@@ -391,5 +710,162 @@ mod tests {
         let chunk = &chunks[0];
         assert_eq!(&text[chunk.start..chunk.start + chunk.len], "བཀྲ་");
     }
-}
 
+    #[test]
+    fn test_chunks_iterator_matches_make_chunks() {
+        let text = "བཀྲ་ཤིས། hello 123 世界";
+        let chunker = Chunker::new(text);
+
+        let from_iter: Vec<_> = chunker.chunks().map(|c| (c.start, c.len, c.chunk_type)).collect();
+        let from_vec: Vec<_> = chunker
+            .make_chunks()
+            .into_iter()
+            .map(|c| (c.start, c.len, c.chunk_type))
+            .collect();
+
+        assert_eq!(from_iter, from_vec);
+    }
+
+    #[test]
+    fn test_make_chunks_lossless_round_trips() {
+        let text = " བཀྲ་ཤིས་  hello, world! 123  ";
+        let chunker = Chunker::new(text);
+        let chunks = chunker.make_chunks_lossless();
+
+        let reassembled: String = chunks
+            .iter()
+            .map(|c| &text[c.start..c.start + c.len])
+            .collect();
+        assert_eq!(reassembled, text);
+
+        assert!(
+            chunks.iter().any(|c| c.chunk_type == ChunkType::Space),
+            "whitespace should be emitted as standalone Space chunks"
+        );
+    }
+
+    #[test]
+    fn test_subjoined_without_head_is_flagged() {
+        // A bare sub-joined RA (ྲ) with no preceding head consonant
+        let chunker = Chunker::new("ྲ");
+        let chunks = chunker.make_chunks();
+
+        assert_eq!(chunks.len(), 1);
+        assert!(chunks[0].flags.contains(ChunkFlags::SUBJOINED_WITHOUT_HEAD));
+    }
+
+    #[test]
+    fn test_vowel_before_consonant_is_flagged() {
+        // A bare vowel sign (ི) with nothing preceding it
+        let chunker = Chunker::new("ི");
+        let chunks = chunker.make_chunks();
+
+        assert_eq!(chunks.len(), 1);
+        assert!(chunks[0].flags.contains(ChunkFlags::VOWEL_BEFORE_CONSONANT));
+    }
+
+    #[test]
+    fn test_well_formed_syllable_has_no_flags() {
+        let chunker = Chunker::new("བཀྲ་ཤིས་");
+        let chunks = chunker.make_chunks();
+
+        assert!(chunks.iter().all(|c| c.flags.is_empty()));
+    }
+
+    #[test]
+    fn test_head_mark_and_shad_are_separate_chunks() {
+        let chunker = Chunker::new("༄༅། །བཀྲ་ཤིས་");
+        let chunks = chunker.make_chunks();
+
+        assert_eq!(chunks[0].chunk_type, ChunkType::HeadMark);
+        assert_eq!(&chunker.string()[chunks[0].start..chunks[0].start + chunks[0].len], "༄༅");
+
+        assert_eq!(chunks[1].chunk_type, ChunkType::ClosingMark);
+        assert_eq!(&chunker.string()[chunks[1].start..chunks[1].start + chunks[1].len], "། །");
+
+        assert_eq!(chunks[2].chunk_type, ChunkType::Text);
+    }
+
+    #[test]
+    fn test_tsheg_separated_shad_space_is_not_swallowed_in_lossless_mode() {
+        let text = "ལེགས། །སོ";
+        let chunker = Chunker::new(text);
+        let chunks = chunker.make_chunks_lossless();
+
+        let reassembled: String = chunks.iter().map(|c| &text[c.start..c.start + c.len]).collect();
+        assert_eq!(reassembled, text);
+        assert!(chunks.iter().any(|c| c.chunk_type == ChunkType::Space));
+
+        // In lossless mode the compound mark is never recognized across the
+        // space it spans, so each shad falls back to plain Punct rather than
+        // ClosingMark - unlike the non-lossless path (see
+        // `test_head_mark_and_shad_are_separate_chunks`).
+        assert!(chunks.iter().filter(|c| c.chunk_type == ChunkType::Punct).count() >= 2);
+        assert!(!chunks.iter().any(|c| c.chunk_type == ChunkType::ClosingMark));
+    }
+
+    #[test]
+    fn test_double_shad_is_closing_mark() {
+        let chunker = Chunker::new("ལེགས༎");
+        let chunks = chunker.make_chunks();
+
+        assert_eq!(chunks.last().unwrap().chunk_type, ChunkType::ClosingMark);
+    }
+
+    #[test]
+    fn test_chunk_lengths_cover_entire_input() {
+        for text in ["བཀྲ་ཤིས་ བདེ་ལེགས།", "hello world", "123 456", "混合 text བཀྲ"] {
+            let chunker = Chunker::new(text);
+            let chunks = chunker.make_chunks();
+
+            let total: usize = chunks.iter().map(|c| c.len).sum();
+            assert_eq!(total, text.len(), "chunk lens should cover all of {text:?}");
+
+            let mut prev_end = 0;
+            for chunk in &chunks {
+                assert!(chunk.start >= prev_end, "chunk offsets should be monotonically increasing");
+                prev_end = chunk.start + chunk.len;
+            }
+        }
+    }
+
+    #[test]
+    fn test_validated_well_formed_text_has_no_errors() {
+        let chunker = Chunker::new("བཀྲ་ཤིས་");
+        let (chunks, errors) = chunker.make_chunks_validated();
+
+        assert_eq!(chunks.len(), 2);
+        assert!(errors.is_empty());
+    }
+
+    #[test]
+    fn test_validated_dangling_sub_cons_reports_byte_span() {
+        let chunker = Chunker::new("ྲ");
+        let (_, errors) = chunker.make_chunks_validated();
+
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].kind, TokenErrorKind::DanglingSubCons);
+        assert_eq!(errors[0].start, 0);
+        assert_eq!(errors[0].len, "ྲ".len());
+    }
+
+    #[test]
+    fn test_validated_orphan_vowel_is_reported() {
+        let chunker = Chunker::new("ི");
+        let (_, errors) = chunker.make_chunks_validated();
+
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].kind, TokenErrorKind::OrphanVowel);
+    }
+
+    #[test]
+    fn test_validated_double_tsek_spans_both_tseks() {
+        let text = "བཀྲ་་ཤིས";
+        let chunker = Chunker::new(text);
+        let (_, errors) = chunker.make_chunks_validated();
+
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].kind, TokenErrorKind::DoubleTsek);
+        assert_eq!(&text[errors[0].start..errors[0].start + errors[0].len], "་་");
+    }
+}
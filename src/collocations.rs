@@ -0,0 +1,237 @@
+//! Collocation and skip-gram extraction from the token stream.
+//!
+//! This module discovers statistically significant multi-word Tibetan
+//! expressions from raw token frequency statistics, which plain sentence
+//! boundaries cannot surface on their own.
+
+use std::collections::HashMap;
+
+use crate::token::{ChunkType, Token};
+
+/// Tunable parameters for collocation extraction.
+#[derive(Debug, Clone)]
+pub struct CollocationConfig {
+    /// Minimum number of times a pair must co-occur to be considered
+    pub min_count: usize,
+    /// Minimum PMI score to keep a candidate pair
+    pub min_score: f64,
+    /// Maximum gap (in candidate words) allowed between the two members of
+    /// a skip-gram; 0 means bigrams only (strictly adjacent)
+    pub max_skip: usize,
+}
+
+impl Default for CollocationConfig {
+    fn default() -> Self {
+        CollocationConfig {
+            min_count: 2,
+            min_score: 0.0,
+            max_skip: 0,
+        }
+    }
+}
+
+/// A ranked collocation candidate.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Collocation {
+    /// The two member words, in order of occurrence
+    pub words: (String, String),
+    /// Number of times this pair co-occurred within the configured gap
+    pub count: usize,
+    /// Pointwise mutual information score
+    pub score: f64,
+}
+
+/// Check whether a token is eligible to participate in a collocation: a
+/// text word that is not a bare particle.
+fn is_candidate(token: &Token) -> bool {
+    if token.chunk_type != ChunkType::Text || token.syls.is_empty() {
+        return false;
+    }
+
+    !matches!(token.pos.as_deref(), Some("PART"))
+}
+
+/// Extract and score collocations (bigrams and, if `max_skip > 0`,
+/// skip-grams) from a token stream.
+///
+/// Candidate words are counted in isolation from any intervening
+/// punctuation or particle tokens: the candidate stream is built by
+/// filtering `tokens` down to [`is_candidate`] words first, then pairs are
+/// formed from that filtered stream.
+pub fn extract_collocations(tokens: &[Token], config: &CollocationConfig) -> Vec<Collocation> {
+    let candidates: Vec<&Token> = tokens.iter().filter(|t| is_candidate(t)).collect();
+
+    if candidates.len() < 2 {
+        return vec![];
+    }
+
+    let mut unigram_counts: HashMap<String, usize> = HashMap::new();
+    let mut bigram_counts: HashMap<(String, String), usize> = HashMap::new();
+    let mut total_unigrams = 0usize;
+    let mut total_bigrams = 0usize;
+
+    for token in &candidates {
+        *unigram_counts.entry(token.text_cleaned()).or_insert(0) += 1;
+        total_unigrams += 1;
+    }
+
+    for i in 0..candidates.len() {
+        let a = candidates[i].text_cleaned();
+        for gap in 0..=config.max_skip {
+            let j = i + 1 + gap;
+            if j >= candidates.len() {
+                break;
+            }
+            let b = candidates[j].text_cleaned();
+            if a == b {
+                continue;
+            }
+            *bigram_counts.entry((a.clone(), b)).or_insert(0) += 1;
+            total_bigrams += 1;
+        }
+    }
+
+    let n_unigrams = total_unigrams as f64;
+    let n_bigrams = total_bigrams.max(1) as f64;
+
+    let mut collocations: Vec<Collocation> = bigram_counts
+        .into_iter()
+        .filter(|(_, count)| *count >= config.min_count)
+        .map(|((a, b), count)| {
+            let p_a = unigram_counts[&a] as f64 / n_unigrams;
+            let p_b = unigram_counts[&b] as f64 / n_unigrams;
+            let p_ab = count as f64 / n_bigrams;
+            let score = (p_ab / (p_a * p_b)).ln();
+            Collocation {
+                words: (a, b),
+                count,
+                score,
+            }
+        })
+        .filter(|c| c.score >= config.min_score)
+        .collect();
+
+    collocations.sort_by(|a, b| b.score.partial_cmp(&a.score).unwrap_or(std::cmp::Ordering::Equal));
+    collocations
+}
+
+/// Rewrite a token stream, merging any adjacent pair of tokens whose
+/// cleaned text matches an accepted [`Collocation`] into a single
+/// composite token (concatenated `syls` and span), so the result flows
+/// into [`crate::sentence::sentence_tokenize`] as atomic units.
+///
+/// Only strictly adjacent pairs are merged, even if `collocations` were
+/// extracted with `max_skip > 0`, since a skip-gram spans tokens that are
+/// not contiguous in the original stream.
+pub fn merge_collocations(tokens: &[Token], collocations: &[Collocation]) -> Vec<Token> {
+    let accepted: std::collections::HashSet<(&str, &str)> =
+        collocations.iter().map(|c| (c.words.0.as_str(), c.words.1.as_str())).collect();
+
+    let mut merged = Vec::with_capacity(tokens.len());
+    let mut i = 0;
+
+    while i < tokens.len() {
+        if i + 1 < tokens.len() {
+            let a = tokens[i].text_cleaned();
+            let b = tokens[i + 1].text_cleaned();
+            if accepted.contains(&(a.as_str(), b.as_str())) {
+                merged.push(merge_collocation_pair(&tokens[i], &tokens[i + 1]));
+                i += 2;
+                continue;
+            }
+        }
+
+        merged.push(tokens[i].clone());
+        i += 1;
+    }
+
+    merged
+}
+
+/// Merge two adjacent tokens into a single composite collocation token.
+fn merge_collocation_pair(first: &Token, second: &Token) -> Token {
+    let merged_text = format!("{}{}", first.text, second.text);
+    let merged_len = first.len + second.len;
+
+    let mut merged = Token::with_text(merged_text, first.start, merged_len, ChunkType::Text);
+
+    let mut merged_syls = first.syls.clone();
+    merged_syls.extend(second.syls.clone());
+    merged.syls = merged_syls;
+
+    merged.pos = first.pos.clone();
+    merged.lemma = Some(merged.text_cleaned());
+
+    merged
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn make_token(text: &str, pos: Option<&str>) -> Token {
+        let mut token = Token::with_text(text.to_string(), 0, text.len(), ChunkType::Text);
+        token.pos = pos.map(|p| p.to_string());
+        token.syls = text.split('་').filter(|s| !s.is_empty()).map(|s| s.to_string()).collect();
+        token
+    }
+
+    fn punct_token() -> Token {
+        Token::with_text("།".to_string(), 0, "།".len(), ChunkType::Punct)
+    }
+
+    #[test]
+    fn test_extract_collocations_finds_repeated_bigram() {
+        let tokens = vec![
+            make_token("བཀྲ", Some("NOUN")),
+            make_token("ཤིས", Some("NOUN")),
+            punct_token(),
+            make_token("བཀྲ", Some("NOUN")),
+            make_token("ཤིས", Some("NOUN")),
+        ];
+
+        let collocations = extract_collocations(&tokens, &CollocationConfig::default());
+
+        assert_eq!(collocations.len(), 1);
+        assert_eq!(collocations[0].words, ("བཀྲ་".to_string(), "ཤིས་".to_string()));
+        assert_eq!(collocations[0].count, 2);
+    }
+
+    #[test]
+    fn test_extract_collocations_respects_min_count() {
+        let tokens = vec![make_token("ཀུན", Some("NOUN")), make_token("བཟང", Some("NOUN"))];
+
+        let config = CollocationConfig { min_count: 2, ..Default::default() };
+        assert!(extract_collocations(&tokens, &config).is_empty());
+    }
+
+    #[test]
+    fn test_extract_collocations_skips_particles() {
+        let tokens = vec![
+            make_token("བཀྲ", Some("NOUN")),
+            make_token("གི", Some("PART")),
+            make_token("ཤིས", Some("NOUN")),
+        ];
+
+        let config = CollocationConfig { min_count: 1, ..Default::default() };
+        let collocations = extract_collocations(&tokens, &config);
+
+        assert_eq!(collocations.len(), 1);
+        assert_eq!(collocations[0].words, ("བཀྲ་".to_string(), "ཤིས་".to_string()));
+    }
+
+    #[test]
+    fn test_merge_collocations_builds_composite_token() {
+        let tokens = vec![make_token("བཀྲ", Some("NOUN")), make_token("ཤིས", Some("NOUN"))];
+        let collocations = vec![Collocation {
+            words: ("བཀྲ་".to_string(), "ཤིས་".to_string()),
+            count: 2,
+            score: 1.0,
+        }];
+
+        let merged = merge_collocations(&tokens, &collocations);
+
+        assert_eq!(merged.len(), 1);
+        assert_eq!(merged[0].syls, vec!["བཀྲ".to_string(), "ཤིས".to_string()]);
+    }
+}
@@ -0,0 +1,194 @@
+//! Minimal acyclic DAWG compiled from a [`Trie`] by hash-consing identical
+//! suffix subtrees.
+//!
+//! Like [`crate::double_array::DoubleArrayTrie`], a [`Dawg`] is an
+//! immutable, flat representation compiled from a node-per-entry [`Trie`]
+//! for a large dictionary's memory and load-time wins. Where a double
+//! array only flattens the existing tree, [`Dawg::from_trie`] additionally
+//! minimizes it: two subtrees are equivalent iff they share `is_leaf`,
+//! leaf [`WordData`], and an identical set of `(symbol, equivalent-child)`
+//! edges, so productive affix expansions that happen to share a suffix
+//! (e.g. many stems followed by the same case-particle tail) collapse onto
+//! one shared node instead of being duplicated per stem.
+
+use std::collections::HashMap;
+
+use crate::trie::{Trie, TrieNode, WordData};
+
+#[cfg_attr(feature = "mmap", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+struct DawgNode {
+    is_leaf: bool,
+    data: Option<WordData>,
+    /// Outgoing edges, sorted by symbol so [`Dawg::walk`] can binary search.
+    edges: Vec<(String, u32)>,
+}
+
+/// An immutable, minimized DAWG compiled from a [`Trie`].
+///
+/// Has the same `walk(syl, current) -> Option<State>` lookup shape as
+/// [`crate::trie::Trie::walk`] and [`crate::double_array::DoubleArrayTrie::walk`],
+/// so it is a drop-in read-only backend for large inflected dictionaries.
+#[cfg_attr(feature = "mmap", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Clone, Default)]
+pub struct Dawg {
+    nodes: Vec<DawgNode>,
+    root: u32,
+}
+
+/// Hash-conses [`TrieNode`] subtrees bottom-up while building a [`Dawg`].
+#[derive(Default)]
+struct DawgBuilder {
+    nodes: Vec<DawgNode>,
+    signatures: HashMap<DawgNode, u32>,
+}
+
+impl DawgBuilder {
+    /// Minimize `node` and return the id of its (possibly shared) [`DawgNode`].
+    fn minimize(&mut self, node: &TrieNode) -> u32 {
+        let mut edges: Vec<(String, u32)> = node
+            .children
+            .iter()
+            .map(|(sym, child)| (sym.clone(), self.minimize(child)))
+            .collect();
+        edges.sort();
+
+        let candidate = DawgNode {
+            is_leaf: node.is_leaf,
+            data: node.data.clone(),
+            edges,
+        };
+
+        if let Some(&id) = self.signatures.get(&candidate) {
+            return id;
+        }
+
+        let id = self.nodes.len() as u32;
+        self.nodes.push(candidate.clone());
+        self.signatures.insert(candidate, id);
+        id
+    }
+}
+
+impl Dawg {
+    /// The DAWG's root state.
+    pub fn root_state(&self) -> usize {
+        self.root as usize
+    }
+
+    /// Compile and minimize a node-per-entry [`Trie`] into a `Dawg`.
+    pub fn from_trie(trie: &Trie) -> Self {
+        let mut builder = DawgBuilder::default();
+        let root = builder.minimize(trie.root());
+        Dawg { nodes: builder.nodes, root }
+    }
+
+    /// Walk the DAWG by one syllable, returning the next state if the
+    /// transition is valid. Has the same semantics as [`Trie::walk`].
+    pub fn walk(&self, syl: &str, current: Option<usize>) -> Option<usize> {
+        let state = current.unwrap_or(self.root as usize);
+        let node = self.nodes.get(state)?;
+        let idx = node.edges.binary_search_by(|(s, _)| s.as_str().cmp(syl)).ok()?;
+        Some(node.edges[idx].1 as usize)
+    }
+
+    /// Whether `state` marks the end of a valid word.
+    pub fn is_match(&self, state: usize) -> bool {
+        self.nodes.get(state).map(|n| n.is_leaf).unwrap_or(false)
+    }
+
+    /// The dictionary data attached to `state`, if any.
+    pub fn word_data(&self, state: usize) -> Option<&WordData> {
+        self.nodes.get(state).and_then(|n| n.data.as_ref())
+    }
+
+    /// Number of distinct nodes in the minimized DAWG, for comparing
+    /// against the unminimized [`Trie`]'s node count.
+    pub fn node_count(&self) -> usize {
+        self.nodes.len()
+    }
+}
+
+#[cfg(feature = "mmap")]
+impl Dawg {
+    /// Serialize this DAWG to a compact binary blob.
+    pub fn serialize(&self) -> Result<Vec<u8>, crate::double_array::DoubleArrayError> {
+        bincode::serialize(self).map_err(|e| crate::double_array::DoubleArrayError::Serialize(e.to_string()))
+    }
+
+    /// Deserialize a DAWG previously produced by [`Dawg::serialize`].
+    pub fn deserialize(bytes: &[u8]) -> Result<Self, crate::double_array::DoubleArrayError> {
+        bincode::deserialize(bytes).map_err(|e| crate::double_array::DoubleArrayError::Serialize(e.to_string()))
+    }
+
+    /// Serialize this DAWG and write it to `path`, for fast loading via
+    /// [`Dawg::load_mmap`].
+    pub fn save(&self, path: impl AsRef<std::path::Path>) -> Result<(), crate::double_array::DoubleArrayError> {
+        let bytes = self.serialize()?;
+        std::fs::write(path, bytes).map_err(|e| crate::double_array::DoubleArrayError::Io(e.to_string()))
+    }
+
+    /// Memory-map a DAWG previously written by [`Dawg::save`] and
+    /// deserialize it; the whole file is decoded into an owned `Dawg`
+    /// before the first lookup.
+    pub fn load_mmap(path: impl AsRef<std::path::Path>) -> Result<Self, crate::double_array::DoubleArrayError> {
+        let file = std::fs::File::open(path).map_err(|e| crate::double_array::DoubleArrayError::Io(e.to_string()))?;
+        let mmap = unsafe { memmap2::Mmap::map(&file) }.map_err(|e| crate::double_array::DoubleArrayError::Io(e.to_string()))?;
+        Self::deserialize(&mmap[..])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::trie::TrieBuilder;
+
+    fn make_test_trie() -> Trie {
+        let tsv = "བཀྲ་ཤིས\tNOUN\t\t\t1000\nབཀྲ་ལེགས\tNOUN\t\t\t500\nབདེ་ལེགས\tNOUN\t\t\t500";
+        let mut builder = TrieBuilder::new();
+        builder.load_tsv(tsv);
+        builder.build()
+    }
+
+    #[test]
+    fn test_dawg_matches_trie_lookups() {
+        let trie = make_test_trie();
+        let dawg = Dawg::from_trie(&trie);
+
+        let s1 = dawg.walk("བཀྲ", Some(dawg.root_state()));
+        assert!(s1.is_some());
+        assert!(!dawg.is_match(s1.unwrap()));
+
+        let s2 = dawg.walk("ཤིས", s1);
+        assert!(s2.is_some());
+        assert!(dawg.is_match(s2.unwrap()));
+        assert_eq!(dawg.word_data(s2.unwrap()).and_then(|d| d.freq), Some(1000));
+    }
+
+    #[test]
+    fn test_dawg_rejects_unknown_transition() {
+        let trie = make_test_trie();
+        let dawg = Dawg::from_trie(&trie);
+
+        let s1 = dawg.walk("ཀ", Some(dawg.root_state()));
+        assert!(s1.is_none());
+    }
+
+    #[test]
+    fn test_dawg_shares_identical_suffix_subtrees() {
+        // "བཀྲ་ལེགས" and "བདེ་ལེགས" end in the identical leaf syllable
+        // "ལེགས" with identical WordData, so the DAWG should fold the two
+        // second-syllable nodes into one shared node instead of storing
+        // each separately, unlike the unminimized node-per-entry Trie.
+        let trie = make_test_trie();
+        let dawg = Dawg::from_trie(&trie);
+
+        let s1 = dawg.walk("བཀྲ", Some(dawg.root_state())).unwrap();
+        let s2 = dawg.walk("བདེ", Some(dawg.root_state())).unwrap();
+        let leaf1 = dawg.walk("ལེགས", Some(s1)).unwrap();
+        let leaf2 = dawg.walk("ལེགས", Some(s2)).unwrap();
+
+        assert_eq!(leaf1, leaf2);
+        assert!(dawg.node_count() < trie.len() * 2 + 1);
+    }
+}
@@ -7,6 +7,8 @@ use std::fs::{self, File};
 use std::io::{self, Cursor};
 use std::path::{Path, PathBuf};
 
+use crate::trie::{Trie, TrieBuilder, WordData};
+
 /// Default dialect pack name
 pub const DEFAULT_DIALECT_PACK: &str = "general";
 
@@ -105,43 +107,123 @@ pub fn download_dialect_pack(
         "https://github.com/{}/releases/download/{}/{}.zip",
         BOTOK_DATA_REPO, version, dialect_name
     );
-    
+
     eprintln!("[INFO] Downloading {} dialect pack (version {})...", dialect_name, version);
-    
+
     // Download the zip file
     let client = reqwest::blocking::Client::builder()
         .user_agent("botok-rs")
         .timeout(std::time::Duration::from_secs(120))
         .build()
         .map_err(|e| DialectPackError::Network(e.to_string()))?;
-    
+
     let response = client.get(&url)
         .send()
         .map_err(|e| DialectPackError::Network(e.to_string()))?;
-    
+
     if !response.status().is_success() {
         return Err(DialectPackError::Network(format!(
             "Failed to download dialect pack: HTTP {}", response.status()
         )));
     }
-    
+
     let bytes = response.bytes()
         .map_err(|e| DialectPackError::Network(e.to_string()))?;
-    
-    // Extract the zip file
+
+    verify_checksum(&client, &url, &bytes)?;
+
+    // Extract into a sibling temp directory first, so a crash or interrupted
+    // extraction never leaves a half-written directory at `pack_path` for
+    // `dialect_pack_exists` to mistake for a complete pack. Remove any stale
+    // temp directory left behind by a previous failed attempt before reusing
+    // the name.
+    let tmp_path = base.join(format!(".{dialect_name}.tmp"));
+    if tmp_path.exists() {
+        fs::remove_dir_all(&tmp_path).map_err(|e| DialectPackError::Io(e.to_string()))?;
+    }
+    fs::create_dir_all(&tmp_path).map_err(|e| DialectPackError::Io(e.to_string()))?;
+
+    if let Err(e) = extract_zip(&bytes, &tmp_path) {
+        let _ = fs::remove_dir_all(&tmp_path);
+        return Err(e);
+    }
+
+    // Atomic swap into place - on the same filesystem this is a single
+    // directory-entry update, so `pack_path` either doesn't exist yet or is
+    // a complete pack, never a partial one.
+    if let Err(e) = fs::rename(&tmp_path, &pack_path) {
+        let _ = fs::remove_dir_all(&tmp_path);
+        return Err(DialectPackError::Io(e.to_string()));
+    }
+
+    eprintln!("[INFO] Download completed!");
+
+    Ok(pack_path)
+}
+
+/// Verify `bytes` against the SHA-256 digest published alongside `url`
+/// (GitHub's convention of a `.sha256` sibling asset next to the archive
+/// itself, containing either a bare hex digest or `sha256sum`-style
+/// `<digest>  <filename>` output). Guards against truncated downloads and
+/// tampered release assets.
+#[cfg(feature = "download")]
+fn verify_checksum(client: &reqwest::blocking::Client, url: &str, bytes: &[u8]) -> Result<(), DialectPackError> {
+    let checksum_url = format!("{url}.sha256");
+
+    let response = client
+        .get(&checksum_url)
+        .send()
+        .map_err(|e| DialectPackError::Network(e.to_string()))?;
+
+    if !response.status().is_success() {
+        return Err(DialectPackError::Network(format!(
+            "Failed to download checksum for dialect pack: HTTP {}", response.status()
+        )));
+    }
+
+    let body = response.text().map_err(|e| DialectPackError::Network(e.to_string()))?;
+    let expected = body
+        .split_whitespace()
+        .next()
+        .ok_or_else(|| DialectPackError::Checksum("empty .sha256 response".into()))?
+        .to_lowercase();
+
+    use sha2::{Digest, Sha256};
+    let mut hasher = Sha256::new();
+    hasher.update(bytes);
+    let actual = hex_encode(&hasher.finalize());
+
+    if actual != expected {
+        return Err(DialectPackError::Checksum(format!(
+            "checksum mismatch for dialect pack archive: expected {expected}, got {actual}"
+        )));
+    }
+
+    Ok(())
+}
+
+/// Render a byte slice as lowercase hex, for comparing a computed SHA-256
+/// digest against a published or recorded one.
+fn hex_encode(bytes: &[u8]) -> String {
+    bytes.iter().map(|b| format!("{b:02x}")).collect()
+}
+
+/// Extract every entry of the zip archive in `bytes` under `dest`.
+#[cfg(feature = "download")]
+fn extract_zip(bytes: &[u8], dest: &Path) -> Result<(), DialectPackError> {
     let cursor = Cursor::new(bytes);
     let mut archive = zip::ZipArchive::new(cursor)
         .map_err(|e| DialectPackError::Zip(e.to_string()))?;
-    
+
     for i in 0..archive.len() {
         let mut file = archive.by_index(i)
             .map_err(|e| DialectPackError::Zip(e.to_string()))?;
-        
+
         let outpath = match file.enclosed_name() {
-            Some(path) => base.join(path),
+            Some(path) => dest.join(path),
             None => continue,
         };
-        
+
         if file.name().ends_with('/') {
             fs::create_dir_all(&outpath)
                 .map_err(|e| DialectPackError::Io(e.to_string()))?;
@@ -158,10 +240,8 @@ pub fn download_dialect_pack(
                 .map_err(|e| DialectPackError::Io(e.to_string()))?;
         }
     }
-    
-    eprintln!("[INFO] Download completed!");
-    
-    Ok(pack_path)
+
+    Ok(())
 }
 
 /// Get a dialect pack, downloading if necessary
@@ -170,7 +250,13 @@ pub fn get_dialect_pack(
     dialect_name: &str,
     base_path: Option<&Path>,
 ) -> Result<PathBuf, DialectPackError> {
-    download_dialect_pack(dialect_name, base_path, None)
+    let path = download_dialect_pack(dialect_name, base_path, None)?;
+
+    if let Some(manifest) = read_manifest(&path) {
+        check_version_compat(&manifest)?;
+    }
+
+    Ok(path)
 }
 
 /// Get the default dialect pack (general), downloading if necessary
@@ -179,27 +265,205 @@ pub fn get_default_dialect_pack() -> Result<PathBuf, DialectPackError> {
     get_dialect_pack(DEFAULT_DIALECT_PACK, None)
 }
 
-/// List all TSV files in a dialect pack's dictionary
-pub fn list_dictionary_files(dialect_pack_path: &Path) -> io::Result<Vec<PathBuf>> {
-    let dict_path = dialect_pack_path.join("dictionary");
-    if !dict_path.is_dir() {
-        return Ok(Vec::new());
+/// Name of the lockfile [`vendor_dialect_packs`] writes to a vendor
+/// directory, recording each pack's resolved version and a content digest -
+/// modeled on cargo's `Cargo.lock` for vendored crates.
+const LOCKFILE_NAME: &str = "dialect-packs.lock";
+
+/// One [`DialectPackLock`] entry: the version a pack was vendored at and
+/// the SHA-256 of its extracted contents, as computed by
+/// [`compute_pack_digest`].
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+struct DialectPackLockEntry {
+    version: String,
+    sha256: String,
+}
+
+/// The `dialect-packs.lock` manifest: every vendored pack's resolved
+/// version and content digest, keyed by pack name.
+#[derive(Debug, Clone, Default, serde::Serialize, serde::Deserialize)]
+struct DialectPackLock {
+    packs: std::collections::HashMap<String, DialectPackLockEntry>,
+}
+
+fn read_lockfile(dir: &Path) -> Option<DialectPackLock> {
+    let content = fs::read_to_string(dir.join(LOCKFILE_NAME)).ok()?;
+    serde_json::from_str(&content).ok()
+}
+
+fn write_lockfile(dir: &Path, lock: &DialectPackLock) -> Result<(), DialectPackError> {
+    let json = serde_json::to_string_pretty(lock).map_err(|e| DialectPackError::Io(e.to_string()))?;
+    fs::write(dir.join(LOCKFILE_NAME), json).map_err(|e| DialectPackError::Io(e.to_string()))
+}
+
+/// Recursively collect every regular file under `dir`.
+fn collect_all_files(dir: &Path, files: &mut Vec<PathBuf>) -> io::Result<()> {
+    for entry in fs::read_dir(dir)? {
+        let path = entry?.path();
+        if path.is_dir() {
+            collect_all_files(&path, files)?;
+        } else {
+            files.push(path);
+        }
     }
-    
+    Ok(())
+}
+
+/// SHA-256 over a pack directory's files (path relative to `pack_path`,
+/// then content), sorted by path for a stable result. Computed the same way
+/// at vendor time and at verification time, so it catches any change to
+/// the extracted pack - edited, added, or removed files - without needing
+/// to keep the original downloaded archive around.
+fn compute_pack_digest(pack_path: &Path) -> io::Result<String> {
+    use sha2::{Digest, Sha256};
+
     let mut files = Vec::new();
-    collect_tsv_files(&dict_path, &mut files)?;
-    Ok(files)
+    collect_all_files(pack_path, &mut files)?;
+    files.sort();
+
+    let mut hasher = Sha256::new();
+    for file in &files {
+        let rel = file.strip_prefix(pack_path).unwrap_or(file);
+        hasher.update(rel.to_string_lossy().as_bytes());
+        hasher.update(fs::read(file)?);
+    }
+    Ok(hex_encode(&hasher.finalize()))
+}
+
+/// Download `names` at `version` into `dest` and write a `dialect-packs.lock`
+/// recording each pack's resolved version and content digest, modeled on
+/// cargo's crate vendoring: once vendored, [`get_vendored_dialect_pack`] (or
+/// [`resolve_dialect_pack`] with `BOTOK_OFFLINE=1`) can resolve the same
+/// packs with no network access, verifying them against the lockfile first.
+#[cfg(feature = "download")]
+pub fn vendor_dialect_packs(dest: &Path, names: &[&str], version: &str) -> Result<(), DialectPackError> {
+    fs::create_dir_all(dest).map_err(|e| DialectPackError::Io(e.to_string()))?;
+
+    let mut lock = read_lockfile(dest).unwrap_or_default();
+
+    for &name in names {
+        let pack_path = download_dialect_pack(name, Some(dest), Some(version))?;
+        let digest = compute_pack_digest(&pack_path).map_err(|e| DialectPackError::Io(e.to_string()))?;
+        lock.packs.insert(name.to_string(), DialectPackLockEntry { version: version.to_string(), sha256: digest });
+    }
+
+    write_lockfile(dest, &lock)
+}
+
+/// Environment variable that forces strictly offline, vendor-only pack
+/// resolution even when the `download` feature is compiled in - for CI and
+/// air-gapped deployments that have a vendor directory but would rather not
+/// rely on the `download` feature being off at compile time.
+pub const OFFLINE_ENV_VAR: &str = "BOTOK_OFFLINE";
+
+fn offline_mode() -> bool {
+    cfg!(not(feature = "download")) || std::env::var(OFFLINE_ENV_VAR).as_deref() == Ok("1")
+}
+
+/// Resolve `dialect_name` strictly from `vendor_dir`, verifying it against
+/// `vendor_dir`'s `dialect-packs.lock` (written by [`vendor_dialect_packs`])
+/// before returning. Never touches the network.
+pub fn get_vendored_dialect_pack(dialect_name: &str, vendor_dir: &Path) -> Result<PathBuf, DialectPackError> {
+    let pack_path = vendor_dir.join(dialect_name);
+    if !dialect_pack_exists(dialect_name, Some(vendor_dir)) {
+        return Err(DialectPackError::NotFound(format!(
+            "dialect pack '{dialect_name}' not found under vendor directory {}",
+            vendor_dir.display()
+        )));
+    }
+
+    let lock = read_lockfile(vendor_dir).ok_or_else(|| {
+        DialectPackError::NotFound(format!("no {LOCKFILE_NAME} found in {}", vendor_dir.display()))
+    })?;
+    let entry = lock.packs.get(dialect_name).ok_or_else(|| {
+        DialectPackError::NotFound(format!("'{dialect_name}' is not recorded in {LOCKFILE_NAME}"))
+    })?;
+
+    let actual = compute_pack_digest(&pack_path).map_err(|e| DialectPackError::Io(e.to_string()))?;
+    if actual != entry.sha256 {
+        return Err(DialectPackError::Integrity(format!(
+            "vendored pack '{dialect_name}' failed its integrity check: {LOCKFILE_NAME} expects sha256 {}, found {}",
+            entry.sha256, actual
+        )));
+    }
+
+    Ok(pack_path)
+}
+
+/// Resolve a dialect pack the same way [`get_dialect_pack`] does when
+/// networking is available, but fall back to strictly vendored,
+/// lockfile-verified resolution (see [`get_vendored_dialect_pack`]) when the
+/// `download` feature is off or [`OFFLINE_ENV_VAR`] is set to `"1"`. This is
+/// the entry point reproducible, network-free builds should use in place of
+/// [`get_dialect_pack`].
+pub fn resolve_dialect_pack(dialect_name: &str, base_path: Option<&Path>) -> Result<PathBuf, DialectPackError> {
+    if offline_mode() {
+        let vendor_dir = base_path.map(PathBuf::from).unwrap_or_else(default_base_path);
+        return get_vendored_dialect_pack(dialect_name, &vendor_dir);
+    }
+
+    #[cfg(feature = "download")]
+    {
+        get_dialect_pack(dialect_name, base_path)
+    }
+    #[cfg(not(feature = "download"))]
+    {
+        unreachable!("offline_mode() is always true without the \"download\" feature")
+    }
+}
+
+/// List all TSV files in a dialect pack's dictionary
+pub fn list_dictionary_files(dialect_pack_path: &Path) -> io::Result<Vec<PathBuf>> {
+    list_tsv_files_in(&dialect_pack_path.join("dictionary"))
 }
 
 /// List all TSV files in a dialect pack's adjustments
 pub fn list_adjustment_files(dialect_pack_path: &Path) -> io::Result<Vec<PathBuf>> {
-    let adj_path = dialect_pack_path.join("adjustments");
-    if !adj_path.is_dir() {
+    list_tsv_files_in(&dialect_pack_path.join("adjustments"))
+}
+
+/// List TSV files in a dialect pack's `dictionary/words` section - the
+/// main, auto-inflected word list.
+fn list_words_files(dialect_pack_path: &Path) -> io::Result<Vec<PathBuf>> {
+    list_tsv_files_in(&dialect_pack_path.join("dictionary").join("words"))
+}
+
+/// List TSV files in a dialect pack's `dictionary/words_non_inflected`
+/// section - words loaded as-is, without generating affixed forms.
+fn list_words_non_inflected_files(dialect_pack_path: &Path) -> io::Result<Vec<PathBuf>> {
+    list_tsv_files_in(&dialect_pack_path.join("dictionary").join("words_non_inflected"))
+}
+
+/// List TSV files in a dialect pack's `dictionary/words_skrt` section -
+/// Sanskrit transliterations, loaded with [`WordData::skrt`] set.
+fn list_words_skrt_files(dialect_pack_path: &Path) -> io::Result<Vec<PathBuf>> {
+    list_tsv_files_in(&dialect_pack_path.join("dictionary").join("words_skrt"))
+}
+
+/// List TSV files in a custom overlay's `words` section - entries that
+/// overwrite matching base-pack entries instead of merging with them.
+fn list_custom_words_files(custom_path: &Path) -> io::Result<Vec<PathBuf>> {
+    list_tsv_files_in(&custom_path.join("words"))
+}
+
+/// List TSV files in a custom overlay's `words_skrt` section.
+fn list_custom_words_skrt_files(custom_path: &Path) -> io::Result<Vec<PathBuf>> {
+    list_tsv_files_in(&custom_path.join("words_skrt"))
+}
+
+/// List files in a custom overlay's `remove` section - one word form per
+/// line, deactivated in the resolved trie.
+fn list_remove_files(custom_path: &Path) -> io::Result<Vec<PathBuf>> {
+    list_tsv_files_in(&custom_path.join("remove"))
+}
+
+fn list_tsv_files_in(dir: &Path) -> io::Result<Vec<PathBuf>> {
+    if !dir.is_dir() {
         return Ok(Vec::new());
     }
-    
+
     let mut files = Vec::new();
-    collect_tsv_files(&adj_path, &mut files)?;
+    collect_tsv_files(dir, &mut files)?;
     Ok(files)
 }
 
@@ -218,6 +482,331 @@ fn collect_tsv_files(dir: &Path, files: &mut Vec<PathBuf>) -> io::Result<()> {
     Ok(())
 }
 
+/// Parse one `form\tpos\tlemma\tsense\tfreq` TSV line into its form and a
+/// `WordData`, skipping blank lines and `#` comments. Mirrors
+/// [`TrieBuilder::load_tsv`]'s field layout, but (unlike that auto-inflecting
+/// loader) does not attach a [`crate::token::Sense`] - callers here only
+/// care about overwriting `pos`/`lemma`/`freq` as-is.
+fn parse_word_line(line: &str, skrt: bool) -> Option<(&str, WordData)> {
+    let line = line.trim();
+    if line.is_empty() || line.starts_with('#') {
+        return None;
+    }
+
+    let parts: Vec<&str> = line.split('\t').collect();
+    let form = parts[0];
+    let pos = parts.get(1).and_then(|s| if s.is_empty() { None } else { Some(s.to_string()) });
+    let lemma = parts.get(2).and_then(|s| if s.is_empty() { None } else { Some(s.to_string()) });
+    let freq = parts.get(4).and_then(|s| s.trim().parse::<u32>().ok());
+
+    Some((form, WordData { pos, lemma, freq, skrt, ..Default::default() }))
+}
+
+/// Load a `words`/`words_skrt` overlay file's entries into `builder`,
+/// generating inflected forms the same way the base dictionary does.
+fn load_custom_words(builder: &mut TrieBuilder, content: &str, skrt: bool) {
+    for line in content.lines() {
+        if let Some((form, data)) = parse_word_line(line, skrt) {
+            builder.add_inflected_word(form, Some(data));
+        }
+    }
+}
+
+/// Tombstone every form listed in a `remove` file (one form per line).
+fn apply_removals(trie: &mut Trie, content: &str) {
+    for line in content.lines() {
+        let form = line.trim();
+        if form.is_empty() || form.starts_with('#') {
+            continue;
+        }
+        trie.deactivate_word(form);
+    }
+}
+
+/// Apply a `form\tnew_pos\tnew_lemma` adjustment file, rewriting only the
+/// `pos`/`lemma` of entries that are already present; rows for words not in
+/// the trie are silently skipped.
+fn apply_adjustments(trie: &mut Trie, content: &str) {
+    for line in content.lines() {
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+
+        let mut fields = line.split('\t');
+        let Some(form) = fields.next() else { continue };
+        let pos = fields.next().and_then(|s| if s.is_empty() { None } else { Some(s.to_string()) });
+        let lemma = fields.next().and_then(|s| if s.is_empty() { None } else { Some(s.to_string()) });
+
+        let syls: Vec<&str> = form.split('་').filter(|s| !s.is_empty()).collect();
+        trie.adjust_word(&syls, pos, lemma);
+    }
+}
+
+/// Name of the manifest file expected at a dialect pack's root, following
+/// the `package.json`-style descriptor pattern: a name, a version, and the
+/// compatibility constraint readers must satisfy.
+const MANIFEST_FILE: &str = "manifest.json";
+
+/// A dialect pack's `manifest.json` - its name, version, the sections it
+/// includes (`words`, `words_skrt`, `words_non_inflected`, ...), and the
+/// minimum `botok-rs` crate version it was built for. Reading this up front
+/// lets [`get_dialect_pack`] and [`Config::build_trie`] reject an
+/// incompatible pack with a clear error instead of failing later with a
+/// malformed trie.
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct PackManifest {
+    pub name: String,
+    pub version: String,
+    pub sections: Vec<String>,
+    pub min_crate_version: String,
+}
+
+/// Read and parse `pack_path`'s `manifest.json`, if present. Packs without a
+/// manifest are treated as compatible - the manifest is additive metadata,
+/// not (yet) a requirement.
+fn read_manifest(pack_path: &Path) -> Option<PackManifest> {
+    let content = fs::read_to_string(pack_path.join(MANIFEST_FILE)).ok()?;
+    serde_json::from_str(&content).ok()
+}
+
+/// Parse a dotted `major.minor.patch` version string into a comparable
+/// tuple, treating missing or unparsable components as zero.
+fn parse_version(version: &str) -> (u32, u32, u32) {
+    let mut parts = version.split('.').map(|p| p.parse::<u32>().unwrap_or(0));
+    (parts.next().unwrap_or(0), parts.next().unwrap_or(0), parts.next().unwrap_or(0))
+}
+
+/// Check that the running crate version satisfies `manifest.min_crate_version`.
+fn check_version_compat(manifest: &PackManifest) -> Result<(), DialectPackError> {
+    if parse_version(crate::VERSION) < parse_version(&manifest.min_crate_version) {
+        return Err(DialectPackError::IncompatibleVersion {
+            pack_name: manifest.name.clone(),
+            required: manifest.min_crate_version.clone(),
+            running: crate::VERSION.to_string(),
+        });
+    }
+    Ok(())
+}
+
+/// Name of the precompiled base-trie cache file written next to a dialect
+/// pack's `dictionary/` directory by [`Config::build_trie`].
+#[cfg(feature = "mmap")]
+const BASE_CACHE_FILE: &str = "base_trie.bin";
+
+/// A [`Config::build_trie`] base-trie cache: the compiled trie plus the
+/// crate version and source-file fingerprint it was built from, so a later
+/// load can tell whether it's still valid.
+#[cfg(feature = "mmap")]
+#[derive(serde::Serialize, serde::Deserialize)]
+struct BaseTrieCache {
+    crate_version: String,
+    source_fingerprint: u64,
+    trie: Trie,
+}
+
+/// Fingerprint a set of source files by path, modification time, and size -
+/// cheap to compute on every load without reading file contents, and
+/// changes whenever a file is added, removed, or edited.
+#[cfg(feature = "mmap")]
+fn fingerprint_files(files: &[PathBuf]) -> u64 {
+    use std::hash::{Hash, Hasher};
+
+    let mut sorted: Vec<&PathBuf> = files.iter().collect();
+    sorted.sort();
+
+    let mut hasher = std::collections::hash_map::DefaultHasher::new();
+    for file in sorted {
+        file.hash(&mut hasher);
+        if let Ok(metadata) = fs::metadata(file) {
+            metadata.len().hash(&mut hasher);
+            if let Ok(modified) = metadata.modified() {
+                modified.hash(&mut hasher);
+            }
+        }
+    }
+    hasher.finish()
+}
+
+/// Load the base-trie cache for `base_path`, if one exists and its crate
+/// version and source fingerprint still match `base_files`.
+#[cfg(feature = "mmap")]
+fn load_cached_base_trie(base_path: &Path, base_files: &[PathBuf]) -> Option<Trie> {
+    let bytes = fs::read(base_path.join(BASE_CACHE_FILE)).ok()?;
+    let cache: BaseTrieCache = bincode::deserialize(&bytes).ok()?;
+
+    if cache.crate_version != crate::VERSION || cache.source_fingerprint != fingerprint_files(base_files) {
+        return None;
+    }
+
+    Some(cache.trie)
+}
+
+/// Write `trie` to `base_path`'s base-trie cache, tagged with the current
+/// crate version and `base_files`' fingerprint. Best-effort: a failure to
+/// write (e.g. a read-only pack directory) is silently ignored, since the
+/// cache is purely an optimization.
+#[cfg(feature = "mmap")]
+fn save_cached_base_trie(base_path: &Path, base_files: &[PathBuf], trie: &Trie) {
+    let cache = BaseTrieCache {
+        crate_version: crate::VERSION.to_string(),
+        source_fingerprint: fingerprint_files(base_files),
+        trie: trie.clone(),
+    };
+
+    if let Ok(bytes) = bincode::serialize(&cache) {
+        let _ = fs::write(base_path.join(BASE_CACHE_FILE), bytes);
+    }
+}
+
+/// A resolved dialect-pack profile: a base pack plus an optional local
+/// overlay directory, mirroring the section layering of the Python botok
+/// `Config` class.
+///
+/// [`Config::build_trie`] applies every section in a fixed order, so
+/// overlay entries always win over the base pack: the base pack's
+/// `words`/`words_non_inflected`/`words_skrt` sections load first, then the
+/// overlay's own `words`/`words_skrt` overwrite any matching entries, then
+/// its `remove` list deactivates entries, and finally its `adjustments`
+/// rewrite `pos`/`lemma` on entries that are still present.
+pub struct Config {
+    base_path: PathBuf,
+    custom_path: Option<PathBuf>,
+}
+
+impl Config {
+    /// Resolve `dialect_or_path` as the base pack: if it names an existing
+    /// local directory, that directory is used directly; otherwise it's
+    /// treated as a dialect pack name under `base_path` (see
+    /// [`dialect_pack_path`]).
+    pub fn new(dialect_or_path: &str, base_path: Option<&Path>) -> Self {
+        let as_path = Path::new(dialect_or_path);
+        let base = if as_path.is_dir() {
+            as_path.to_path_buf()
+        } else {
+            dialect_pack_path(dialect_or_path, base_path)
+        };
+
+        Config { base_path: base, custom_path: None }
+    }
+
+    /// Layer a local overlay directory - its own `words`, `words_skrt`,
+    /// `remove`, and `adjustments` sections - on top of the base pack.
+    pub fn with_custom_dir(mut self, path: impl Into<PathBuf>) -> Self {
+        self.custom_path = Some(path.into());
+        self
+    }
+
+    /// The resolved base pack directory.
+    pub fn base_path(&self) -> &Path {
+        &self.base_path
+    }
+
+    /// The base pack's `manifest.json`, if it has one.
+    pub fn manifest(&self) -> Option<PackManifest> {
+        read_manifest(&self.base_path)
+    }
+
+    /// Resolve every section and apply them to a fresh [`Trie`] in order.
+    ///
+    /// Building the base pack's sections from raw TSV is the dominant cost
+    /// for a large dictionary, so (with the `mmap` feature) the result is
+    /// cached next to the pack, tagged with the crate version and a
+    /// fingerprint of the source TSV files: as long as neither has
+    /// changed, later calls deserialize the cached base trie directly and
+    /// skip `TrieBuilder` entirely.
+    #[cfg(feature = "mmap")]
+    pub fn build_trie(&self) -> io::Result<Trie> {
+        if let Some(manifest) = self.manifest() {
+            check_version_compat(&manifest)?;
+        }
+
+        let base_files = self.base_section_files()?;
+
+        if let Some(trie) = load_cached_base_trie(&self.base_path, &base_files) {
+            return self.apply_custom_overlay(trie);
+        }
+
+        let trie = self.build_base_trie()?;
+        save_cached_base_trie(&self.base_path, &base_files, &trie);
+        self.apply_custom_overlay(trie)
+    }
+
+    /// Resolve every section and apply them to a fresh [`Trie`] in order.
+    #[cfg(not(feature = "mmap"))]
+    pub fn build_trie(&self) -> io::Result<Trie> {
+        if let Some(manifest) = self.manifest() {
+            check_version_compat(&manifest)?;
+        }
+
+        let trie = self.build_base_trie()?;
+        self.apply_custom_overlay(trie)
+    }
+
+    #[cfg(feature = "mmap")]
+    fn base_section_files(&self) -> io::Result<Vec<PathBuf>> {
+        let mut files = list_words_files(&self.base_path)?;
+        files.extend(list_words_skrt_files(&self.base_path)?);
+        files.extend(list_words_non_inflected_files(&self.base_path)?);
+        Ok(files)
+    }
+
+    fn build_base_trie(&self) -> io::Result<Trie> {
+        let mut builder = TrieBuilder::with_inflection();
+        for file in list_words_files(&self.base_path)? {
+            if let Ok(content) = fs::read_to_string(&file) {
+                builder.load_tsv(&content);
+            }
+        }
+        for file in list_words_skrt_files(&self.base_path)? {
+            if let Ok(content) = fs::read_to_string(&file) {
+                load_custom_words(&mut builder, &content, true);
+            }
+        }
+        let mut trie = builder.build();
+
+        let mut non_inflected = TrieBuilder::new();
+        for file in list_words_non_inflected_files(&self.base_path)? {
+            if let Ok(content) = fs::read_to_string(&file) {
+                non_inflected.load_tsv(&content);
+            }
+        }
+        trie.merge(&non_inflected.build());
+
+        Ok(trie)
+    }
+
+    fn apply_custom_overlay(&self, mut trie: Trie) -> io::Result<Trie> {
+        if let Some(custom_path) = &self.custom_path {
+            let mut overwrite_builder = TrieBuilder::with_inflection();
+            for file in list_custom_words_files(custom_path)? {
+                if let Ok(content) = fs::read_to_string(&file) {
+                    load_custom_words(&mut overwrite_builder, &content, false);
+                }
+            }
+            for file in list_custom_words_skrt_files(custom_path)? {
+                if let Ok(content) = fs::read_to_string(&file) {
+                    load_custom_words(&mut overwrite_builder, &content, true);
+                }
+            }
+            trie.merge(&overwrite_builder.build());
+
+            for file in list_remove_files(custom_path)? {
+                if let Ok(content) = fs::read_to_string(&file) {
+                    apply_removals(&mut trie, &content);
+                }
+            }
+            for file in list_adjustment_files(custom_path)? {
+                if let Ok(content) = fs::read_to_string(&file) {
+                    apply_adjustments(&mut trie, &content);
+                }
+            }
+        }
+
+        Ok(trie)
+    }
+}
+
 /// Errors that can occur when working with dialect packs
 #[derive(Debug)]
 pub enum DialectPackError {
@@ -229,6 +818,12 @@ pub enum DialectPackError {
     Io(String),
     /// Dialect pack not found
     NotFound(String),
+    /// The downloaded archive's SHA-256 didn't match the published digest
+    Checksum(String),
+    /// A vendored pack's contents didn't match its `dialect-packs.lock` entry
+    Integrity(String),
+    /// The pack's manifest requires a newer `botok-rs` than is running
+    IncompatibleVersion { pack_name: String, required: String, running: String },
 }
 
 impl std::fmt::Display for DialectPackError {
@@ -238,12 +833,25 @@ impl std::fmt::Display for DialectPackError {
             DialectPackError::Zip(msg) => write!(f, "Zip error: {}", msg),
             DialectPackError::Io(msg) => write!(f, "IO error: {}", msg),
             DialectPackError::NotFound(msg) => write!(f, "Dialect pack not found: {}", msg),
+            DialectPackError::Checksum(msg) => write!(f, "Checksum error: {}", msg),
+            DialectPackError::Integrity(msg) => write!(f, "Integrity error: {}", msg),
+            DialectPackError::IncompatibleVersion { pack_name, required, running } => write!(
+                f,
+                "Dialect pack '{}' requires botok-rs >= {}, but the running crate is {}",
+                pack_name, required, running
+            ),
         }
     }
 }
 
 impl std::error::Error for DialectPackError {}
 
+impl From<DialectPackError> for io::Error {
+    fn from(err: DialectPackError) -> Self {
+        io::Error::other(err.to_string())
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -259,5 +867,186 @@ mod tests {
         let path = dialect_pack_path("general", None);
         assert!(path.to_string_lossy().contains("general"));
     }
+
+    #[test]
+    fn test_config_layers_base_pack_and_custom_overlay() {
+        let root = std::env::temp_dir().join("botok_rs_test_config_layers");
+        let _ = fs::remove_dir_all(&root);
+
+        let base = root.join("pack");
+        fs::create_dir_all(base.join("dictionary").join("words")).unwrap();
+        fs::write(
+            base.join("dictionary").join("words").join("main.tsv"),
+            "བཀྲ་ཤིས\tNOUN\t\t\t1000\nབདེ་ལེགས\tNOUN\t\t\t500\n",
+        )
+        .unwrap();
+
+        let custom = root.join("custom");
+        fs::create_dir_all(custom.join("words")).unwrap();
+        fs::write(custom.join("words").join("overwrite.tsv"), "བཀྲ་ཤིས\tADJ\t\t\t2000\n").unwrap();
+        fs::create_dir_all(custom.join("remove")).unwrap();
+        fs::write(custom.join("remove").join("forms.tsv"), "བདེ་ལེགས\n").unwrap();
+        fs::create_dir_all(custom.join("adjustments")).unwrap();
+        fs::write(custom.join("adjustments").join("adj.tsv"), "བཀྲ་ཤིས\tADJ2\tlemma_val\n").unwrap();
+
+        let config = Config::new(base.to_str().unwrap(), None).with_custom_dir(&custom);
+        let trie = config.build_trie().unwrap();
+
+        assert!(trie.has_word(&["བཀྲ", "ཤིས"]));
+        assert!(!trie.has_word(&["བདེ", "ལེགས"]));
+
+        let data = trie.get_word_data(&["བཀྲ", "ཤིས"]).unwrap();
+        assert_eq!(data.pos.as_deref(), Some("ADJ2"));
+        assert_eq!(data.lemma.as_deref(), Some("lemma_val"));
+
+        let _ = fs::remove_dir_all(&root);
+    }
+
+    #[test]
+    fn test_build_trie_rejects_pack_requiring_newer_crate_version() {
+        let root = std::env::temp_dir().join("botok_rs_test_manifest_incompatible");
+        let _ = fs::remove_dir_all(&root);
+        fs::create_dir_all(root.join("dictionary").join("words")).unwrap();
+        fs::write(root.join("dictionary").join("words").join("main.tsv"), "བཀྲ་ཤིས\tNOUN\t\t\t1000\n").unwrap();
+        fs::write(
+            root.join("manifest.json"),
+            r#"{"name":"general","version":"1.0.0","sections":["words"],"min_crate_version":"999.0.0"}"#,
+        )
+        .unwrap();
+
+        let config = Config::new(root.to_str().unwrap(), None);
+        let err = config.build_trie().unwrap_err();
+        assert!(err.to_string().contains("999.0.0"));
+
+        let _ = fs::remove_dir_all(&root);
+    }
+
+    #[test]
+    fn test_build_trie_accepts_pack_manifest_with_satisfied_version() {
+        let root = std::env::temp_dir().join("botok_rs_test_manifest_compatible");
+        let _ = fs::remove_dir_all(&root);
+        fs::create_dir_all(root.join("dictionary").join("words")).unwrap();
+        fs::write(root.join("dictionary").join("words").join("main.tsv"), "བཀྲ་ཤིས\tNOUN\t\t\t1000\n").unwrap();
+        fs::write(
+            root.join("manifest.json"),
+            r#"{"name":"general","version":"1.0.0","sections":["words"],"min_crate_version":"0.1.0"}"#,
+        )
+        .unwrap();
+
+        let config = Config::new(root.to_str().unwrap(), None);
+        assert!(config.manifest().is_some());
+        let trie = config.build_trie().unwrap();
+        assert!(trie.has_word(&["བཀྲ", "ཤིས"]));
+
+        let _ = fs::remove_dir_all(&root);
+    }
+
+    #[test]
+    fn test_config_resolves_local_path_without_base_path() {
+        let root = std::env::temp_dir().join("botok_rs_test_config_local_path");
+        let _ = fs::remove_dir_all(&root);
+        fs::create_dir_all(root.join("dictionary").join("words")).unwrap();
+        fs::write(root.join("dictionary").join("words").join("main.tsv"), "བཀྲ་ཤིས\tNOUN\t\t\t1000\n").unwrap();
+
+        let config = Config::new(root.to_str().unwrap(), None);
+        assert_eq!(config.base_path(), root.as_path());
+
+        let trie = config.build_trie().unwrap();
+        assert!(trie.has_word(&["བཀྲ", "ཤིས"]));
+
+        let _ = fs::remove_dir_all(&root);
+    }
+
+    #[test]
+    #[cfg(feature = "mmap")]
+    fn test_build_trie_reuses_base_cache_until_source_files_change() {
+        let root = std::env::temp_dir().join("botok_rs_test_build_trie_cache");
+        let _ = fs::remove_dir_all(&root);
+        fs::create_dir_all(root.join("dictionary").join("words")).unwrap();
+        let words_file = root.join("dictionary").join("words").join("main.tsv");
+        fs::write(&words_file, "བཀྲ་ཤིས\tNOUN\t\t\t1000\n").unwrap();
+
+        let config = Config::new(root.to_str().unwrap(), None);
+        let trie = config.build_trie().unwrap();
+        assert!(trie.has_word(&["བཀྲ", "ཤིས"]));
+        assert!(root.join(BASE_CACHE_FILE).exists());
+
+        // Rebuilding without touching the source files should hit the cache.
+        let cached = load_cached_base_trie(&root, &config.base_section_files().unwrap());
+        assert!(cached.is_some());
+        assert!(cached.unwrap().has_word(&["བཀྲ", "ཤིས"]));
+
+        // Editing a source file invalidates the cache.
+        fs::write(&words_file, "བདེ་ལེགས\tNOUN\t\t\t500\n").unwrap();
+        let stale = load_cached_base_trie(&root, &config.base_section_files().unwrap());
+        assert!(stale.is_none());
+
+        let _ = fs::remove_dir_all(&root);
+    }
+
+    fn write_vendored_pack(pack_path: &Path) {
+        fs::create_dir_all(pack_path.join("dictionary").join("words")).unwrap();
+        fs::write(
+            pack_path.join("dictionary").join("words").join("main.tsv"),
+            "བཀྲ་ཤིས\tNOUN\t\t\t1000\n",
+        )
+        .unwrap();
+    }
+
+    #[test]
+    fn test_get_vendored_dialect_pack_accepts_matching_lockfile() {
+        let root = std::env::temp_dir().join("botok_rs_test_vendor_ok");
+        let _ = fs::remove_dir_all(&root);
+        let pack_path = root.join("general");
+        write_vendored_pack(&pack_path);
+
+        let digest = compute_pack_digest(&pack_path).unwrap();
+        let mut lock = DialectPackLock::default();
+        lock.packs.insert("general".to_string(), DialectPackLockEntry { version: "1.0.0".to_string(), sha256: digest });
+        write_lockfile(&root, &lock).unwrap();
+
+        let resolved = get_vendored_dialect_pack("general", &root).unwrap();
+        assert_eq!(resolved, pack_path);
+
+        let _ = fs::remove_dir_all(&root);
+    }
+
+    #[test]
+    fn test_get_vendored_dialect_pack_rejects_tampered_pack() {
+        let root = std::env::temp_dir().join("botok_rs_test_vendor_tampered");
+        let _ = fs::remove_dir_all(&root);
+        let pack_path = root.join("general");
+        write_vendored_pack(&pack_path);
+
+        let digest = compute_pack_digest(&pack_path).unwrap();
+        let mut lock = DialectPackLock::default();
+        lock.packs.insert("general".to_string(), DialectPackLockEntry { version: "1.0.0".to_string(), sha256: digest });
+        write_lockfile(&root, &lock).unwrap();
+
+        fs::write(
+            pack_path.join("dictionary").join("words").join("main.tsv"),
+            "བཀྲ་ཤིས\tNOUN\t\t\t9999\n",
+        )
+        .unwrap();
+
+        let err = get_vendored_dialect_pack("general", &root).unwrap_err();
+        assert!(matches!(err, DialectPackError::Integrity(_)));
+
+        let _ = fs::remove_dir_all(&root);
+    }
+
+    #[test]
+    fn test_get_vendored_dialect_pack_missing_lockfile_entry() {
+        let root = std::env::temp_dir().join("botok_rs_test_vendor_no_lock_entry");
+        let _ = fs::remove_dir_all(&root);
+        let pack_path = root.join("general");
+        write_vendored_pack(&pack_path);
+        write_lockfile(&root, &DialectPackLock::default()).unwrap();
+
+        let err = get_vendored_dialect_pack("general", &root).unwrap_err();
+        assert!(matches!(err, DialectPackError::NotFound(_)));
+
+        let _ = fs::remove_dir_all(&root);
+    }
 }
 
@@ -0,0 +1,323 @@
+//! Compact double-array trie backend for large dictionaries.
+//!
+//! [`crate::trie::Trie`] is a node-per-entry structure with owned
+//! children, which is simple but scales poorly for a full-size Tibetan
+//! lexicon loaded at startup. `DoubleArrayTrie` compiles a built `Trie`
+//! into a flat `base`/`check` array representation (the double-array trie
+//! technique used by double-array-trie/cedarwood-style segmenters), which
+//! can be saved to disk and loaded back from a memory map. Loading
+//! deserializes the whole file into owned arrays; lookups do not run
+//! directly off the mapped bytes.
+//!
+//! A transition from state `s` on syllable `syl` is valid when
+//! `next = base[s] + code(syl)` satisfies `check[next] == s`, mirroring
+//! [`crate::trie::Trie::walk`]'s `(syl, current) -> Option<next>` shape so
+//! callers can swap backends without changing how they drive the walk.
+
+use std::collections::{HashMap, HashSet};
+
+use crate::trie::{Trie, TrieNode, WordData};
+
+/// An immutable, flat double-array trie compiled from a [`Trie`].
+#[derive(Debug, Clone, Default)]
+pub struct DoubleArrayTrie {
+    base: Vec<i32>,
+    check: Vec<i32>,
+    syllable_codes: HashMap<String, i32>,
+    leaf_states: HashSet<usize>,
+    terminal_data: HashMap<usize, WordData>,
+}
+
+impl DoubleArrayTrie {
+    /// The double array's root state.
+    pub fn root_state(&self) -> usize {
+        0
+    }
+
+    /// Compile a node-per-entry [`Trie`] into a flat double-array form.
+    pub fn from_trie(trie: &Trie) -> Self {
+        let root = trie.root();
+        let syllable_codes = assign_syllable_codes(root);
+
+        let mut base = vec![0i32; 2];
+        let mut check = vec![-1i32; 2];
+        let mut used = vec![false; 2];
+        let mut leaf_states = HashSet::new();
+        let mut terminal_data = HashMap::new();
+
+        used[0] = true;
+        if root.is_leaf {
+            leaf_states.insert(0);
+            if let Some(ref data) = root.data {
+                terminal_data.insert(0, data.clone());
+            }
+        }
+
+        insert_children(root, 0, &syllable_codes, &mut base, &mut check, &mut used, &mut leaf_states, &mut terminal_data);
+
+        DoubleArrayTrie {
+            base,
+            check,
+            syllable_codes,
+            leaf_states,
+            terminal_data,
+        }
+    }
+
+    /// Walk the double array by one syllable, returning the next state if
+    /// the transition is valid. Has the same semantics as
+    /// [`crate::trie::Trie::walk`].
+    pub fn walk(&self, syl: &str, current: Option<usize>) -> Option<usize> {
+        let state = current.unwrap_or(0);
+        let code = *self.syllable_codes.get(syl)?;
+        let next = *self.base.get(state)? + code;
+
+        if next < 0 {
+            return None;
+        }
+        let next = next as usize;
+
+        if next < self.check.len() && self.check[next] == state as i32 {
+            Some(next)
+        } else {
+            None
+        }
+    }
+
+    /// Whether `state` marks the end of a valid word.
+    pub fn is_match(&self, state: usize) -> bool {
+        self.leaf_states.contains(&state)
+    }
+
+    /// The dictionary data attached to `state`, if any.
+    pub fn word_data(&self, state: usize) -> Option<&WordData> {
+        self.terminal_data.get(&state)
+    }
+
+    /// Number of distinct words compiled into this double array.
+    pub fn len(&self) -> usize {
+        self.leaf_states.len()
+    }
+
+    /// Whether this double array has no words.
+    pub fn is_empty(&self) -> bool {
+        self.leaf_states.is_empty()
+    }
+}
+
+/// Assign a stable, compact integer code (starting at 1; 0 is reserved) to
+/// every distinct syllable reachable in the trie.
+fn assign_syllable_codes(root: &TrieNode) -> HashMap<String, i32> {
+    let mut syllables: Vec<String> = Vec::new();
+    let mut stack = vec![root];
+
+    while let Some(node) = stack.pop() {
+        for (syl, child) in &node.children {
+            syllables.push(syl.clone());
+            stack.push(child);
+        }
+    }
+
+    syllables.sort();
+    syllables.dedup();
+    syllables.into_iter().enumerate().map(|(i, syl)| (syl, (i + 1) as i32)).collect()
+}
+
+/// Recursively place `node`'s children into the double array starting from
+/// `state`, then recurse into each child.
+#[allow(clippy::too_many_arguments)]
+fn insert_children(
+    node: &TrieNode,
+    state: usize,
+    codes: &HashMap<String, i32>,
+    base: &mut Vec<i32>,
+    check: &mut Vec<i32>,
+    used: &mut Vec<bool>,
+    leaf_states: &mut HashSet<usize>,
+    terminal_data: &mut HashMap<usize, WordData>,
+) {
+    if node.children.is_empty() {
+        return;
+    }
+
+    let mut children: Vec<(i32, &TrieNode)> =
+        node.children.iter().map(|(syl, child)| (codes[syl], child.as_ref())).collect();
+    children.sort_by_key(|(code, _)| *code);
+
+    let chosen_base = find_base(&children, used);
+    ensure_capacity(base, check, used, chosen_base, &children);
+    base[state] = chosen_base;
+
+    for &(code, child) in &children {
+        let next = (chosen_base + code) as usize;
+        check[next] = state as i32;
+        used[next] = true;
+
+        if child.is_leaf {
+            leaf_states.insert(next);
+            if let Some(ref data) = child.data {
+                terminal_data.insert(next, data.clone());
+            }
+        }
+    }
+
+    for &(code, child) in &children {
+        let next = (chosen_base + code) as usize;
+        insert_children(child, next, codes, base, check, used, leaf_states, terminal_data);
+    }
+}
+
+/// Find the smallest base offset such that every child's transition slot
+/// (`base + code`) is currently unused.
+fn find_base(children: &[(i32, &TrieNode)], used: &[bool]) -> i32 {
+    let mut candidate = 1i32;
+
+    loop {
+        let fits = children.iter().all(|&(code, _)| {
+            let next = candidate + code;
+            next >= 0 && (next as usize >= used.len() || !used[next as usize])
+        });
+
+        if fits {
+            return candidate;
+        }
+        candidate += 1;
+    }
+}
+
+/// Grow `base`/`check`/`used` so every child's transition slot under
+/// `chosen_base` is addressable.
+fn ensure_capacity(base: &mut Vec<i32>, check: &mut Vec<i32>, used: &mut Vec<bool>, chosen_base: i32, children: &[(i32, &TrieNode)]) {
+    let max_idx = children.iter().map(|&(code, _)| (chosen_base + code) as usize).max().unwrap_or(0);
+
+    if max_idx >= base.len() {
+        base.resize(max_idx + 1, 0);
+        check.resize(max_idx + 1, -1);
+        used.resize(max_idx + 1, false);
+    }
+}
+
+/// Errors that can occur saving or memory-mapping a double-array trie.
+#[cfg(feature = "mmap")]
+#[derive(Debug)]
+pub enum DoubleArrayError {
+    /// IO error reading/writing the trie file
+    Io(String),
+    /// Error (de)serializing the binary trie format
+    Serialize(String),
+}
+
+#[cfg(feature = "mmap")]
+impl std::fmt::Display for DoubleArrayError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            DoubleArrayError::Io(msg) => write!(f, "IO error: {}", msg),
+            DoubleArrayError::Serialize(msg) => write!(f, "Serialize error: {}", msg),
+        }
+    }
+}
+
+#[cfg(feature = "mmap")]
+impl std::error::Error for DoubleArrayError {}
+
+#[cfg(feature = "mmap")]
+impl DoubleArrayTrie {
+    /// Serialize this double array to `path` in a flat binary format
+    /// suitable for later loading with [`DoubleArrayTrie::load_mmap`].
+    pub fn save(&self, path: impl AsRef<std::path::Path>) -> Result<(), DoubleArrayError> {
+        let bytes = bincode::serialize(self).map_err(|e| DoubleArrayError::Serialize(e.to_string()))?;
+        std::fs::write(path, bytes).map_err(|e| DoubleArrayError::Io(e.to_string()))
+    }
+
+    /// Memory-map a double array previously written by
+    /// [`DoubleArrayTrie::save`] and deserialize it; the whole file is
+    /// decoded into an owned `DoubleArrayTrie` before the first lookup.
+    pub fn load_mmap(path: impl AsRef<std::path::Path>) -> Result<Self, DoubleArrayError> {
+        let file = std::fs::File::open(path).map_err(|e| DoubleArrayError::Io(e.to_string()))?;
+        let mmap = unsafe { memmap2::Mmap::map(&file) }.map_err(|e| DoubleArrayError::Io(e.to_string()))?;
+
+        bincode::deserialize(&mmap[..]).map_err(|e| DoubleArrayError::Serialize(e.to_string()))
+    }
+}
+
+#[cfg(feature = "mmap")]
+impl serde::Serialize for DoubleArrayTrie {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("DoubleArrayTrie", 5)?;
+        state.serialize_field("base", &self.base)?;
+        state.serialize_field("check", &self.check)?;
+        state.serialize_field("syllable_codes", &self.syllable_codes)?;
+        state.serialize_field("leaf_states", &self.leaf_states.iter().copied().collect::<Vec<usize>>())?;
+        state.serialize_field("terminal_data", &self.terminal_data)?;
+        state.end()
+    }
+}
+
+#[cfg(feature = "mmap")]
+impl<'de> serde::Deserialize<'de> for DoubleArrayTrie {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        #[derive(serde::Deserialize)]
+        struct Raw {
+            base: Vec<i32>,
+            check: Vec<i32>,
+            syllable_codes: HashMap<String, i32>,
+            leaf_states: Vec<usize>,
+            terminal_data: HashMap<usize, WordData>,
+        }
+
+        let raw = Raw::deserialize(deserializer)?;
+        Ok(DoubleArrayTrie {
+            base: raw.base,
+            check: raw.check,
+            syllable_codes: raw.syllable_codes,
+            leaf_states: raw.leaf_states.into_iter().collect(),
+            terminal_data: raw.terminal_data,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::trie::TrieBuilder;
+
+    fn make_test_trie() -> Trie {
+        let tsv = "བཀྲ་ཤིས\tNOUN\t\t\t1000\nབདེ་ལེགས\tNOUN\t\t\t500";
+        let mut builder = TrieBuilder::new();
+        builder.load_tsv(tsv);
+        builder.build()
+    }
+
+    #[test]
+    fn test_double_array_matches_trie_lookups() {
+        let trie = make_test_trie();
+        let da = DoubleArrayTrie::from_trie(&trie);
+
+        let s1 = da.walk("བཀྲ", Some(da.root_state()));
+        assert!(s1.is_some());
+        assert!(!da.is_match(s1.unwrap()));
+
+        let s2 = da.walk("ཤིས", s1);
+        assert!(s2.is_some());
+        assert!(da.is_match(s2.unwrap()));
+        assert_eq!(da.word_data(s2.unwrap()).and_then(|d| d.freq), Some(1000));
+    }
+
+    #[test]
+    fn test_double_array_rejects_unknown_transition() {
+        let trie = make_test_trie();
+        let da = DoubleArrayTrie::from_trie(&trie);
+
+        let s1 = da.walk("ཀ", Some(da.root_state()));
+        assert!(s1.is_none());
+    }
+
+    #[test]
+    fn test_double_array_len() {
+        let trie = make_test_trie();
+        let da = DoubleArrayTrie::from_trie(&trie);
+
+        assert_eq!(da.len(), trie.len());
+    }
+}
@@ -0,0 +1,308 @@
+//! Keyword and keyphrase extraction over tokenized text.
+//!
+//! This module builds on [`crate::sentence::Sentence`] and
+//! [`crate::sentence::Paragraph`] to surface the most salient words in a
+//! token stream, which plain boundary tokenization cannot provide on its
+//! own.
+
+use std::collections::HashMap;
+
+use crate::sentence::{Paragraph, Sentence};
+use crate::token::{ChunkType, Token};
+
+/// TextRank-based keyword and keyphrase extraction.
+pub mod text_rank {
+    use super::*;
+
+    /// Part-of-speech tags kept as keyword candidates.
+    const CANDIDATE_POS: &[&str] = &["NOUN", "VERB", "ADJ"];
+
+    /// Dagdra suffixes (པ་/པོ་/བ་/བོ་) that mark a merged particle rather
+    /// than a content word.
+    const DAGDRA_SUFFIXES: &[&str] = &["པ", "པོ", "བ", "བོ"];
+
+    /// A single scored keyword.
+    #[derive(Debug, Clone, PartialEq)]
+    pub struct Keyword {
+        /// The candidate token's cleaned text
+        pub text: String,
+        /// Final weighted PageRank score
+        pub score: f64,
+        /// Part-of-speech of the candidate, if known
+        pub pos: Option<String>,
+    }
+
+    /// A multi-syllable keyphrase formed by merging adjacent high-ranking
+    /// candidates.
+    #[derive(Debug, Clone, PartialEq)]
+    pub struct KeyPhrase {
+        /// The merged phrase text
+        pub text: String,
+        /// Combined score (sum of the member keywords' scores)
+        pub score: f64,
+    }
+
+    /// Tunable parameters for the TextRank algorithm.
+    #[derive(Debug, Clone)]
+    pub struct TextRankConfig {
+        /// Co-occurrence window size (in candidate tokens)
+        pub window: usize,
+        /// PageRank damping factor
+        pub damping: f64,
+        /// Maximum number of PageRank iterations
+        pub max_iterations: usize,
+        /// Convergence threshold on the max score delta between iterations
+        pub convergence_threshold: f64,
+    }
+
+    impl Default for TextRankConfig {
+        fn default() -> Self {
+            TextRankConfig {
+                window: 4,
+                damping: 0.85,
+                max_iterations: 100,
+                convergence_threshold: 1e-4,
+            }
+        }
+    }
+
+    /// Check whether a token is eligible as a keyword candidate.
+    fn is_candidate(token: &Token) -> bool {
+        if token.chunk_type != ChunkType::Text || token.syls.is_empty() {
+            return false;
+        }
+
+        let Some(ref pos) = token.pos else {
+            return false;
+        };
+
+        if !CANDIDATE_POS.contains(&pos.as_str()) {
+            return false;
+        }
+
+        // Drop forms ending in a dagdra particle, even if merged into the
+        // token (e.g. "བཀྲ་ཤིས་པ" should not itself be treated as a noun
+        // root).
+        if let Some(last_syl) = token.syls.last() {
+            if DAGDRA_SUFFIXES.contains(&last_syl.as_str()) {
+                return false;
+            }
+        }
+
+        true
+    }
+
+    /// Extract the top-`n` keywords from a token slice.
+    pub fn extract_keywords(tokens: &[Token], top_n: usize) -> Vec<Keyword> {
+        extract_keywords_with_config(tokens, top_n, &TextRankConfig::default())
+    }
+
+    /// Extract the top-`n` keywords from a token slice using a custom
+    /// configuration.
+    pub fn extract_keywords_with_config(
+        tokens: &[Token],
+        top_n: usize,
+        config: &TextRankConfig,
+    ) -> Vec<Keyword> {
+        let candidates: Vec<&Token> = tokens.iter().filter(|t| is_candidate(t)).collect();
+
+        if candidates.is_empty() {
+            return vec![];
+        }
+
+        let graph = build_graph(&candidates, config.window);
+        let scores = weighted_page_rank(&graph, config);
+
+        let mut keywords: Vec<Keyword> = scores
+            .into_iter()
+            .map(|(text, score)| {
+                let pos = candidates
+                    .iter()
+                    .find(|t| t.text_cleaned() == text)
+                    .and_then(|t| t.pos.clone());
+                Keyword { text, score, pos }
+            })
+            .collect();
+
+        keywords.sort_by(|a, b| b.score.partial_cmp(&a.score).unwrap_or(std::cmp::Ordering::Equal));
+        keywords.truncate(top_n);
+        keywords
+    }
+
+    /// Extract the top-`n` keywords from a [`Sentence`].
+    pub fn extract_keywords_from_sentence(sentence: &Sentence, top_n: usize) -> Vec<Keyword> {
+        extract_keywords(&sentence.tokens, top_n)
+    }
+
+    /// Extract the top-`n` keywords from a [`Paragraph`].
+    pub fn extract_keywords_from_paragraph(paragraph: &Paragraph, top_n: usize) -> Vec<Keyword> {
+        let tokens: Vec<Token> = paragraph
+            .sentences
+            .iter()
+            .flat_map(|s| s.tokens.iter().cloned())
+            .collect();
+        extract_keywords(&tokens, top_n)
+    }
+
+    /// Extract the top-`n` keyphrases, merging adjacent high-ranking
+    /// candidate tokens into multi-syllable phrases.
+    pub fn extract_keyphrases(tokens: &[Token], top_n: usize) -> Vec<KeyPhrase> {
+        let keywords = extract_keywords(tokens, tokens.len().max(top_n));
+        let scored: HashMap<&str, f64> = keywords.iter().map(|k| (k.text.as_str(), k.score)).collect();
+
+        let mut phrases: Vec<KeyPhrase> = Vec::new();
+        let mut i = 0;
+        let candidate_texts: Vec<Option<String>> = tokens
+            .iter()
+            .map(|t| {
+                if is_candidate(t) {
+                    Some(t.text_cleaned())
+                } else {
+                    None
+                }
+            })
+            .collect();
+
+        while i < candidate_texts.len() {
+            match &candidate_texts[i] {
+                Some(text) if scored.contains_key(text.as_str()) => {
+                    let mut members = vec![text.clone()];
+                    let mut score = scored[text.as_str()];
+                    let mut j = i + 1;
+
+                    while j < candidate_texts.len() {
+                        match &candidate_texts[j] {
+                            Some(next_text) if scored.contains_key(next_text.as_str()) => {
+                                members.push(next_text.clone());
+                                score += scored[next_text.as_str()];
+                                j += 1;
+                            }
+                            _ => break,
+                        }
+                    }
+
+                    // Each member's cleaned text already ends in a tsek.
+                    phrases.push(KeyPhrase {
+                        text: members.concat(),
+                        score,
+                    });
+                    i = j;
+                }
+                _ => i += 1,
+            }
+        }
+
+        phrases.sort_by(|a, b| b.score.partial_cmp(&a.score).unwrap_or(std::cmp::Ordering::Equal));
+        phrases.truncate(top_n);
+        phrases
+    }
+
+    /// Build an undirected, weighted co-occurrence graph over candidate
+    /// tokens: an edge (or weight increment) is added between any two
+    /// candidates within a sliding window of `window` tokens.
+    fn build_graph(candidates: &[&Token], window: usize) -> HashMap<String, HashMap<String, f64>> {
+        let mut graph: HashMap<String, HashMap<String, f64>> = HashMap::new();
+
+        for token in candidates {
+            graph.entry(token.text_cleaned()).or_default();
+        }
+
+        for i in 0..candidates.len() {
+            let text_i = candidates[i].text_cleaned();
+            for j in (i + 1)..candidates.len().min(i + 1 + window) {
+                let text_j = candidates[j].text_cleaned();
+                if text_i == text_j {
+                    continue;
+                }
+
+                *graph.entry(text_i.clone()).or_default().entry(text_j.clone()).or_insert(0.0) += 1.0;
+                *graph.entry(text_j.clone()).or_default().entry(text_i.clone()).or_insert(0.0) += 1.0;
+            }
+        }
+
+        graph
+    }
+
+    /// Run weighted PageRank over the co-occurrence graph until the maximum
+    /// score delta falls below the configured threshold or the iteration
+    /// cap is reached.
+    fn weighted_page_rank(
+        graph: &HashMap<String, HashMap<String, f64>>,
+        config: &TextRankConfig,
+    ) -> HashMap<String, f64> {
+        let mut scores: HashMap<String, f64> = graph.keys().map(|k| (k.clone(), 1.0)).collect();
+
+        let out_weight: HashMap<String, f64> = graph
+            .iter()
+            .map(|(node, edges)| (node.clone(), edges.values().sum()))
+            .collect();
+
+        for _ in 0..config.max_iterations {
+            let mut next_scores = HashMap::with_capacity(scores.len());
+            let mut max_delta: f64 = 0.0;
+
+            for (node, edges) in graph {
+                let mut incoming = 0.0;
+                for (neighbor, weight) in edges {
+                    let neighbor_out = out_weight.get(neighbor).copied().unwrap_or(0.0);
+                    if neighbor_out > 0.0 {
+                        incoming += (weight / neighbor_out) * scores[neighbor];
+                    }
+                }
+
+                let new_score = (1.0 - config.damping) + config.damping * incoming;
+                max_delta = max_delta.max((new_score - scores[node]).abs());
+                next_scores.insert(node.clone(), new_score);
+            }
+
+            scores = next_scores;
+            if max_delta < config.convergence_threshold {
+                break;
+            }
+        }
+
+        scores
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+
+        fn make_token(text: &str, pos: &str) -> Token {
+            let mut token = Token::with_text(text.to_string(), 0, text.len(), ChunkType::Text);
+            token.pos = Some(pos.to_string());
+            token.syls = text.split('་').filter(|s| !s.is_empty()).map(|s| s.to_string()).collect();
+            token
+        }
+
+        #[test]
+        fn test_extract_keywords_ranks_repeated_nouns_highest() {
+            let tokens = vec![
+                make_token("བཀྲ", "NOUN"),
+                make_token("ཤིས", "VERB"),
+                make_token("བཀྲ", "NOUN"),
+                make_token("ལེགས", "ADJ"),
+                make_token("ཀུན", "PART"),
+            ];
+
+            let keywords = extract_keywords(&tokens, 10);
+
+            assert!(!keywords.is_empty());
+            assert_eq!(keywords[0].text, "བཀྲ་");
+        }
+
+        #[test]
+        fn test_extract_keywords_empty() {
+            assert!(extract_keywords(&[], 5).is_empty());
+        }
+
+        #[test]
+        fn test_extract_keyphrases_merges_adjacent_candidates() {
+            let tokens = vec![make_token("བཀྲ", "NOUN"), make_token("ཤིས", "NOUN")];
+            let phrases = extract_keyphrases(&tokens, 5);
+
+            assert_eq!(phrases.len(), 1);
+            assert_eq!(phrases[0].text, "བཀྲ་ཤིས་");
+        }
+    }
+}
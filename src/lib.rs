@@ -43,22 +43,45 @@
 //!
 //! This library can be compiled as a Python extension module. See the README for details.
 
+pub mod automaton;
 pub mod char_categories;
+#[cfg(feature = "mmap")]
+pub mod cdb;
 pub mod chunker;
+pub mod collocations;
+pub mod dawg;
+pub mod dialect_pack;
+pub mod double_array;
+pub mod keywords;
+pub mod modifiers;
+pub mod search;
+pub mod sentence;
+pub mod syllable;
 pub mod token;
 pub mod tokenizer;
 pub mod trie;
+pub mod vocab;
 
 // Python bindings (only compiled when the "python" feature is enabled)
 #[cfg(feature = "python")]
 pub mod python;
 
 // Re-export main types for convenience
+pub use automaton::{Automaton, StreamMatch, StreamMatcher};
 pub use char_categories::{get_char_category, BoString, CharCategory};
-pub use chunker::{Chunk, Chunker};
+#[cfg(feature = "mmap")]
+pub use cdb::CdbTrie;
+pub use chunker::{Chunk, Chunker, TokenError, TokenErrorKind};
+pub use dawg::Dawg;
+pub use dialect_pack::Config;
+pub use sentence::{
+    chunk_for_window, paragraph_tokenize, sentence_tokenize, Paragraph, Sentence, SentenceSegmenter,
+    SentenceTokenizer, WindowChunk,
+};
 pub use token::{ChunkType, Sense, Token};
-pub use tokenizer::{SimpleTokenizer, Tokenizer};
-pub use trie::{AffixInfo, Trie, TrieBuilder, TrieNode, WordData};
+pub use tokenizer::{normalize_tibetan, SimpleTokenizer, Tokenizer};
+pub use trie::{AffixInfo, Trie, TrieBuilder, TrieNode, TrieOverlay, WordData};
+pub use vocab::{Encoding, VocabTokenizer};
 
 /// Version of the library
 pub const VERSION: &str = env!("CARGO_PKG_VERSION");
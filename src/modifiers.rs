@@ -3,8 +3,11 @@
 //! This module provides functions to modify tokens after initial tokenization,
 //! including splitting affixed particles, merging dagdra, and generating lemmas.
 
+use std::collections::HashMap;
+
 use crate::syllable::{is_dagdra, TSEK};
-use crate::token::{ChunkType, Token};
+use crate::token::{ChunkType, Sense, Token};
+use crate::trie::Trie;
 
 /// Split tokens that contain affixed particles.
 ///
@@ -40,47 +43,70 @@ pub fn split_affixed(tokens: &mut Vec<Token>) {
     }
 }
 
-/// Split a token at the affix boundary
+/// Split a token at the affix boundary.
+///
+/// The affix is fused onto the *end* of the token's last syllable - this is
+/// exactly how [`crate::syllable::SylComponents::get_all_affixed`] builds it
+/// (`format!("{}{}", base_syl, affix)`), and `affix_len` is the affix's own
+/// character count (see [`crate::token::AffixationInfo`]) - so the cut point
+/// is `affix_len` characters from the end of that syllable, not the
+/// syllable itself.
 fn split_token_at_affix(token: &Token, affix_len: usize) -> (Token, Token) {
     let syls = &token.syls;
-    
-    // Find the split point in the text
-    // The affix is at the end of the last syllable
+
     let last_syl = syls.last().unwrap();
     let last_syl_chars: Vec<char> = last_syl.chars().collect();
     let split_char_idx = last_syl_chars.len() - affix_len;
-    
-    // Calculate byte position for split
+
     let host_syl: String = last_syl_chars[..split_char_idx].iter().collect();
     let particle_syl: String = last_syl_chars[split_char_idx..].iter().collect();
-    
-    // Create host token (all but the affix)
+
     let mut host_syls: Vec<String> = syls[..syls.len() - 1].to_vec();
     if !host_syl.is_empty() {
-        host_syls.push(host_syl);
+        host_syls.push(host_syl.clone());
     }
-    
+
     let host_text = host_syls.join(&TSEK.to_string());
     let host_len = host_text.len();
-    
+
     let mut host = Token::with_text(
         host_text,
         token.start,
         host_len,
         ChunkType::Text,
     );
+
+    // get_all_affixed strips a word-final འ from the base syllable before
+    // fusing on the affix (its `aa` flag) - the surface host text never
+    // spells it, so reattach it in the lemma only.
+    let mut lemma_syls = host_syls.clone();
+    if token.affixation.as_ref().map_or(false, |a| a.aa) {
+        match lemma_syls.last_mut() {
+            Some(last) => last.push('འ'),
+            None => lemma_syls.push("འ".to_string()),
+        }
+    }
+    // Mark which particle was detached right in the lemma (syntaxdot's
+    // `ab#zeichnen` separable-prefix encoding), so the split is never lossy:
+    // `unsplit_affixed` can later undo it without consulting anything but
+    // the two tokens themselves.
+    let marked_lemma = format!("{}#{}", lemma_syls.join(&TSEK.to_string()), particle_syl);
     host.syls = host_syls;
     host.pos = token.pos.clone();
-    host.lemma = token.lemma.clone();
+    host.lemma = Some(marked_lemma);
     host.freq = token.freq;
     host.is_affix_host = true;
     host.senses = token.senses.clone();
-    
-    // Create particle token
-    let particle_text = format!("{}{}", particle_syl, TSEK);
-    let particle_start = token.start + host_len;
+
+    // The affix is only ever separated from the host by a tsek when it
+    // consumes the whole last syllable (the ordinary inter-syllable tsek
+    // still applies there); when it's fused onto the tail of that
+    // syllable, it directly abuts the host with no separator to account for.
+    let gap = if host_syl.is_empty() { TSEK.len_utf8() } else { 0 };
+    let particle_text = particle_syl.clone();
+    let particle_start = token.start + host_len + gap;
     let particle_len = particle_text.len();
-    
+
     let mut particle = Token::with_text(
         particle_text,
         particle_start,
@@ -90,10 +116,62 @@ fn split_token_at_affix(token: &Token, affix_len: usize) -> (Token, Token) {
     particle.syls = vec![particle_syl];
     particle.pos = Some("PART".to_string());
     particle.is_affix = true;
-    
+
     (host, particle)
 }
 
+/// Undo [`split_affixed`] in place: reconstructs the original unsplit token
+/// from every `is_affix_host` token immediately followed by its `is_affix`
+/// particle, recovering the pre-split surface text, offsets, and lemma from
+/// the host's marked lemma (`"host#particle"`, see [`split_token_at_affix`]).
+/// Gives callers a way to regenerate original offsets after post-processing
+/// a split token stream, or to reverse the split entirely.
+pub fn unsplit_affixed(tokens: &mut Vec<Token>) {
+    let mut i = 0;
+    while i + 1 < tokens.len() {
+        if tokens[i].is_affix_host && tokens[i + 1].is_affix {
+            let merged = unsplit_token_pair(&tokens[i], &tokens[i + 1]);
+            tokens[i] = merged;
+            tokens.remove(i + 1);
+        }
+        i += 1;
+    }
+}
+
+/// Merge a `split_affixed` host/particle pair back into the single token
+/// they were split from.
+fn unsplit_token_pair(host: &Token, particle: &Token) -> Token {
+    // `split_token_at_affix` only leaves a tsek-sized gap between host and
+    // particle offsets when the affix consumed the whole last syllable;
+    // when it's fused onto the host's last syllable the two abut directly.
+    let gap = particle.start.saturating_sub(host.start + host.len);
+    let text = if gap > 0 {
+        format!("{}{}{}", host.text, TSEK, particle.text)
+    } else {
+        format!("{}{}", host.text, particle.text)
+    };
+    let len = host.len + gap + particle.len;
+
+    let mut merged = Token::with_text(text, host.start, len, ChunkType::Text);
+    merged.syls = host.syls.iter().cloned().chain(particle.syls.iter().cloned()).collect();
+    merged.pos = host.pos.clone();
+    merged.freq = host.freq;
+    merged.is_skrt = host.is_skrt;
+    merged.senses = host.senses.clone();
+
+    // Turn the host's "host#particle" marker into a tsek, e.g.
+    // `བཀྲ་ཤིས#ཀྱིས` -> `བཀྲ་ཤིས་ཀྱིས`. For an affix fused onto the host's
+    // last syllable this does not reproduce the surface spelling:
+    // `བཀྲ་ཤིས#འི` becomes `བཀྲ་ཤིས་འི`, not `བཀྲ་ཤིསའི`.
+    merged.lemma = host
+        .lemma
+        .as_ref()
+        .map(|marked| marked.replacen('#', &TSEK.to_string(), 1))
+        .or_else(|| Some(merged.text_cleaned()));
+
+    merged
+}
+
 /// Merge dagdra particles (པ་/པོ་/བ་/བོ་) with the preceding word.
 ///
 /// In Tibetan, these particles are often written separately but should be
@@ -120,6 +198,43 @@ pub fn merge_dagdra(tokens: &mut Vec<Token>) {
     }
 }
 
+/// Like [`merge_dagdra`], but only commits a merge when the fused surface
+/// form is attested in `lexicon` - borrowing the "look up the word before
+/// committing the merge" idea from llama.cpp's `ignore_merges`. This avoids
+/// over-merging a dagdra particle that's genuinely a standalone word rather
+/// than a suffix of the preceding one, at the cost of needing a trie handle.
+pub fn merge_dagdra_with_lexicon(tokens: &mut Vec<Token>, lexicon: &Trie) {
+    if tokens.len() <= 1 {
+        return;
+    }
+
+    let mut i = 0;
+    while i < tokens.len() - 1 {
+        let is_text_pair = tokens[i].chunk_type == ChunkType::Text
+            && tokens[i + 1].chunk_type == ChunkType::Text;
+
+        if is_text_pair
+            && is_dagdra(&tokens[i + 1].text_cleaned())
+            && merged_word_is_attested(&tokens[i], &tokens[i + 1], lexicon)
+        {
+            // Merge the dagdra with the previous token
+            let merged = merge_two_tokens(&tokens[i], &tokens[i + 1]);
+            tokens[i] = merged;
+            tokens.remove(i + 1);
+            // Don't increment i - check if the new merged token can merge again
+        } else {
+            i += 1;
+        }
+    }
+}
+
+/// Whether the surface form formed by fusing `first` and `second`'s
+/// syllables is attested in `lexicon`.
+fn merged_word_is_attested(first: &Token, second: &Token, lexicon: &Trie) -> bool {
+    let syls: Vec<&str> = first.syls.iter().chain(second.syls.iter()).map(String::as_str).collect();
+    lexicon.has_word(&syls)
+}
+
 /// Merge two tokens into one
 fn merge_two_tokens(first: &Token, second: &Token) -> Token {
     let merged_text = format!("{}{}", first.text, second.text);
@@ -183,6 +298,304 @@ pub fn choose_default_senses(tokens: &mut [Token]) {
     }
 }
 
+/// For each text token whose cleaned form isn't attested in `dict`, search
+/// for a close in-dictionary variant - archaic/orthographic variants,
+/// missing or extra tsek, common ya-tags/wa-zur spelling errors - within
+/// `max_dist` syllable-level edits, modeled on nlprule's hunspell-style
+/// spelling-dictionary pass.
+///
+/// On a match, rewrites `lemma` to the closest candidate's canonical form
+/// while leaving `text` untouched, records every candidate in
+/// [`Token::suggestions`], and sets [`Token::spelling_confidence`] so
+/// callers can decide whether to accept the correction. Tokens already
+/// attested in `dict`, and unknown tokens with no close candidate, are left
+/// alone.
+pub fn normalize_spelling(tokens: &mut Vec<Token>, dict: &Trie, max_dist: usize) {
+    for token in tokens.iter_mut() {
+        if token.chunk_type != ChunkType::Text || token.syls.is_empty() {
+            continue;
+        }
+
+        let syls: Vec<&str> = token.syls.iter().map(String::as_str).collect();
+        if dict.has_word(&syls) {
+            continue;
+        }
+
+        let mut candidates = dict.fuzzy_lookup(&token.syls, max_dist);
+        candidates.sort_by_key(|c| c.distance);
+
+        if let Some(best) = candidates.first() {
+            token.lemma = Some(best.syls.join(&TSEK.to_string()));
+            token.spelling_confidence = Some(1.0 / (1.0 + best.distance as f64));
+        }
+        token.suggestions = candidates;
+    }
+}
+
+/// An opt-in [`TokenTransform`] wrapping [`normalize_spelling`], for
+/// inserting dictionary-backed spelling normalization into a
+/// [`ModifierPipeline`]. Deliberately not part of
+/// [`ModifierPipeline::default_pipeline`] - accepting a spelling correction
+/// is a judgment call callers should opt into, not one a default pipeline
+/// should make silently.
+pub struct NormalizeSpelling {
+    dict: Trie,
+    max_dist: usize,
+}
+
+impl NormalizeSpelling {
+    /// Look up unknown tokens' corrections in `dict`, within `max_dist`
+    /// syllable-level edits. Cloning `dict` is cheap - [`Trie`] shares its
+    /// nodes through an `Arc`.
+    pub fn new(dict: Trie, max_dist: usize) -> Self {
+        NormalizeSpelling { dict, max_dist }
+    }
+}
+
+impl TokenTransform for NormalizeSpelling {
+    fn apply(&self, tokens: &mut Vec<Token>) {
+        normalize_spelling(tokens, &self.dict, self.max_dist);
+    }
+}
+
+/// Add-k smoothing floor applied wherever a probability estimate (emission
+/// or transition) would otherwise be exactly zero, so a single unseen sense
+/// or POS bigram can't force its whole Viterbi path to log-probability
+/// negative infinity.
+const SMOOTHING_EPSILON: f64 = 1e-6;
+
+/// One Viterbi state candidate at a token position: a POS tag and the
+/// dictionary sense (if any) it came from.
+struct SenseCandidate<'a> {
+    pos: &'a str,
+    sense: Option<&'a Sense>,
+}
+
+/// Disambiguate each token's sense/POS over the whole sequence via Viterbi,
+/// instead of [`choose_default_senses`]'s per-token frequency heuristic.
+///
+/// States are candidate POS tags for a token - one per dictionary sense;
+/// the emission score is that sense's frequency normalized to a
+/// probability among the token's own senses, and the transition score comes
+/// from `pos_transitions`, a POS bigram model (`HashMap<(String, String),
+/// f64>`) trained from a tagged corpus. Tokens with no senses (punctuation,
+/// non-Tibetan chunks) act as a single fixed pass-through state. The DP
+/// `v[i][s] = max_{s'} v[i-1][s'] * trans(s' -> s) * emit(i, s)` runs in log
+/// space to avoid underflow over long sequences, with backpointers to
+/// recover the winning path; unseen transitions (and degenerate
+/// all-zero-frequency emissions) fall back to [`SMOOTHING_EPSILON`] rather
+/// than ruling a path out entirely.
+pub fn disambiguate_senses(tokens: &mut [Token], pos_transitions: &HashMap<(String, String), f64>) {
+    if tokens.is_empty() {
+        return;
+    }
+
+    let candidates: Vec<Vec<SenseCandidate>> = tokens.iter().map(token_candidates).collect();
+    let emissions: Vec<Vec<f64>> =
+        tokens.iter().zip(&candidates).map(|(t, c)| emission_log_probs(t, c)).collect();
+
+    // v[i][s] holds the best log-probability of any path ending in
+    // candidate `s` at token `i`; back[i][s] is the candidate at token
+    // `i - 1` that path came from.
+    let mut v: Vec<Vec<f64>> = Vec::with_capacity(tokens.len());
+    let mut back: Vec<Vec<usize>> = Vec::with_capacity(tokens.len());
+
+    v.push(emissions[0].clone());
+    back.push(vec![0; candidates[0].len()]);
+
+    for i in 1..tokens.len() {
+        let mut vi = Vec::with_capacity(candidates[i].len());
+        let mut backi = Vec::with_capacity(candidates[i].len());
+
+        for (s, cand) in candidates[i].iter().enumerate() {
+            let mut best_score = f64::NEG_INFINITY;
+            let mut best_prev = 0;
+
+            for (sp, prev_cand) in candidates[i - 1].iter().enumerate() {
+                let trans = transition_log_prob(pos_transitions, prev_cand.pos, cand.pos);
+                let score = v[i - 1][sp] + trans + emissions[i][s];
+                if score > best_score {
+                    best_score = score;
+                    best_prev = sp;
+                }
+            }
+
+            vi.push(best_score);
+            backi.push(best_prev);
+        }
+
+        v.push(vi);
+        back.push(backi);
+    }
+
+    // Backtrack from the best-scoring final state, recovering the winning
+    // candidate's (pos, lemma) as owned values first. `candidates` borrows
+    // `tokens` (each `SenseCandidate` holds `&str`/`&Sense` into a token), so
+    // that borrow must end before `tokens` can be mutated below.
+    let last = tokens.len() - 1;
+    let mut state = v[last]
+        .iter()
+        .enumerate()
+        .max_by(|(_, a), (_, b)| a.partial_cmp(b).unwrap_or(std::cmp::Ordering::Equal))
+        .map(|(s, _)| s)
+        .unwrap_or(0);
+
+    let mut winners: Vec<Option<(Option<String>, Option<String>)>> = Vec::with_capacity(tokens.len());
+    for i in (0..=last).rev() {
+        let candidate = &candidates[i][state];
+        winners.push(candidate.sense.map(|sense| (sense.pos.clone(), sense.lemma.clone())));
+        state = back[i][state];
+    }
+    winners.reverse();
+
+    for (token, winner) in tokens.iter_mut().zip(winners) {
+        if let Some((pos, lemma)) = winner {
+            token.pos = pos;
+            if lemma.is_some() {
+                token.lemma = lemma;
+            }
+        }
+    }
+}
+
+/// The Viterbi candidates for one token: one per dictionary sense, or a
+/// single pass-through candidate (keyed on the token's existing POS, if
+/// any) when it has no senses to choose between.
+fn token_candidates(token: &Token) -> Vec<SenseCandidate<'_>> {
+    if token.senses.is_empty() {
+        return vec![SenseCandidate { pos: token.pos.as_deref().unwrap_or(""), sense: None }];
+    }
+
+    token
+        .senses
+        .iter()
+        .map(|sense| SenseCandidate { pos: sense.pos.as_deref().unwrap_or(""), sense: Some(sense) })
+        .collect()
+}
+
+/// Normalize each candidate's sense frequency into a log-probability among
+/// the token's own senses. Pass-through (senseless) tokens have exactly one
+/// candidate with log-probability zero (probability 1).
+fn emission_log_probs(token: &Token, candidates: &[SenseCandidate]) -> Vec<f64> {
+    if token.senses.is_empty() {
+        return vec![0.0];
+    }
+
+    let freqs: Vec<f64> =
+        candidates.iter().map(|c| c.sense.and_then(|s| s.freq).unwrap_or(0) as f64 + SMOOTHING_EPSILON).collect();
+    let total: f64 = freqs.iter().sum();
+
+    freqs.iter().map(|f| (f / total).ln()).collect()
+}
+
+/// The log-probability of transitioning from `prev_pos` to `pos`, falling
+/// back to [`SMOOTHING_EPSILON`] for any bigram `pos_transitions` doesn't
+/// cover.
+fn transition_log_prob(pos_transitions: &HashMap<(String, String), f64>, prev_pos: &str, pos: &str) -> f64 {
+    pos_transitions
+        .get(&(prev_pos.to_string(), pos.to_string()))
+        .copied()
+        .unwrap_or(SMOOTHING_EPSILON)
+        .max(SMOOTHING_EPSILON)
+        .ln()
+}
+
+/// A single post-processing pass over a token list, modeled on syntaxdot's
+/// `Transform` abstraction: each pass only knows how to mutate `tokens` in
+/// place, so [`ModifierPipeline`] can compose, reorder, or drop passes
+/// without any of them knowing about the others.
+pub trait TokenTransform {
+    /// Apply this pass to `tokens` in place.
+    fn apply(&self, tokens: &mut Vec<Token>);
+}
+
+/// [`split_affixed`] as a [`TokenTransform`].
+pub struct SplitAffixed;
+
+impl TokenTransform for SplitAffixed {
+    fn apply(&self, tokens: &mut Vec<Token>) {
+        split_affixed(tokens);
+    }
+}
+
+/// [`merge_dagdra`] as a [`TokenTransform`].
+pub struct MergeDagdra;
+
+impl TokenTransform for MergeDagdra {
+    fn apply(&self, tokens: &mut Vec<Token>) {
+        merge_dagdra(tokens);
+    }
+}
+
+/// [`generate_default_lemmas`] as a [`TokenTransform`].
+pub struct GenerateDefaultLemmas;
+
+impl TokenTransform for GenerateDefaultLemmas {
+    fn apply(&self, tokens: &mut Vec<Token>) {
+        generate_default_lemmas(tokens);
+    }
+}
+
+/// [`choose_default_senses`] as a [`TokenTransform`].
+pub struct ChooseDefaultSenses;
+
+impl TokenTransform for ChooseDefaultSenses {
+    fn apply(&self, tokens: &mut Vec<Token>) {
+        choose_default_senses(tokens);
+    }
+}
+
+/// An ordered sequence of [`TokenTransform`] passes, run in place over a
+/// token list.
+///
+/// Unlike [`apply_all_modifiers`]'s fixed four-step sequence, a pipeline is
+/// built up one pass at a time, so callers can insert their own passes
+/// (custom normalizers, domain-specific merges) between built-in ones,
+/// disable individual steps, or reorder them.
+///
+/// ```
+/// use botok_rs::modifiers::{ModifierPipeline, SplitAffixed, MergeDagdra, GenerateDefaultLemmas, ChooseDefaultSenses};
+///
+/// let pipeline = ModifierPipeline::new()
+///     .with(SplitAffixed)
+///     .with(MergeDagdra)
+///     .with(GenerateDefaultLemmas)
+///     .with(ChooseDefaultSenses);
+/// ```
+#[derive(Default)]
+pub struct ModifierPipeline(Vec<Box<dyn TokenTransform>>);
+
+impl ModifierPipeline {
+    /// An empty pipeline.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The built-in sequence [`apply_all_modifiers`] runs, as a pipeline:
+    /// split affixed particles (if `split_affixes`), merge dagdra
+    /// particles, generate default lemmas, then choose default senses.
+    pub fn default_pipeline(split_affixes: bool) -> Self {
+        let mut pipeline = Self::new();
+        if split_affixes {
+            pipeline = pipeline.with(SplitAffixed);
+        }
+        pipeline.with(MergeDagdra).with(GenerateDefaultLemmas).with(ChooseDefaultSenses)
+    }
+
+    /// Append `transform` to the end of the pipeline.
+    pub fn with(mut self, transform: impl TokenTransform + 'static) -> Self {
+        self.0.push(Box::new(transform));
+        self
+    }
+
+    /// Run every pass in order over `tokens`.
+    pub fn apply(&self, tokens: &mut Vec<Token>) {
+        for transform in &self.0 {
+            transform.apply(tokens);
+        }
+    }
+}
+
 /// Apply all post-processing steps to a token list.
 ///
 /// This is the main entry point for token modification, applying:
@@ -190,19 +603,78 @@ pub fn choose_default_senses(tokens: &mut [Token]) {
 /// 2. Merge dagdra particles
 /// 3. Generate default lemmas
 /// 4. Choose default senses
+///
+/// Equivalent to running [`ModifierPipeline::default_pipeline`]; use the
+/// pipeline directly to customize the sequence.
 pub fn apply_all_modifiers(tokens: &mut Vec<Token>, split_affixes: bool) {
-    if split_affixes {
-        split_affixed(tokens);
-    }
-    merge_dagdra(tokens);
-    generate_default_lemmas(tokens);
-    choose_default_senses(tokens);
+    ModifierPipeline::default_pipeline(split_affixes).apply(tokens);
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_split_affixed_marks_lemma_with_join_marker() {
+        // ཤིསའི is ཤིས (host) with the genitive འི (len 2) fused directly
+        // onto its last syllable - see `get_all_affixed`'s own example.
+        let mut tokens = vec![Token::with_text("བཀྲ་ཤིསའི".to_string(), 0, 27, ChunkType::Text)];
+        tokens[0].syls = vec!["བཀྲ".to_string(), "ཤིསའི".to_string()];
+        tokens[0].affixation = Some(crate::token::AffixationInfo { len: 2, aa: false });
+
+        split_affixed(&mut tokens);
+
+        assert_eq!(tokens.len(), 2);
+        assert_eq!(tokens[0].syls, vec!["བཀྲ".to_string(), "ཤིས".to_string()]);
+        assert_eq!(tokens[1].syls, vec!["འི".to_string()]);
+        assert_eq!(tokens[0].lemma.as_deref(), Some("བཀྲ་ཤིས#འི"));
+        assert!(tokens[0].is_affix_host);
+        assert!(tokens[1].is_affix);
+    }
+
+    #[test]
+    fn test_unsplit_affixed_round_trips_a_split_token() {
+        let mut tokens = vec![Token::with_text("བཀྲ་ཤིསའི".to_string(), 0, 27, ChunkType::Text)];
+        tokens[0].syls = vec!["བཀྲ".to_string(), "ཤིསའི".to_string()];
+        tokens[0].affixation = Some(crate::token::AffixationInfo { len: 2, aa: false });
+        let original_text = tokens[0].text.clone();
+
+        split_affixed(&mut tokens);
+        assert_eq!(tokens.len(), 2);
+
+        unsplit_affixed(&mut tokens);
+
+        assert_eq!(tokens.len(), 1);
+        assert_eq!(tokens[0].text, original_text);
+        assert_eq!(tokens[0].lemma.as_deref(), Some("བཀྲ་ཤིས་འི"));
+        assert_eq!(tokens[0].start, 0);
+        assert_eq!(tokens[0].len, 27);
+    }
+
+    #[test]
+    fn test_split_affixed_reattaches_aa_in_the_lemma_only() {
+        // ཁས is ཁའ (root ending in འ) with its trailing འ dropped before the
+        // gis affix ས (len 1) fuses on - see `get_all_affixed`'s `aa` flag.
+        // The surface host must stay ཁ (what's actually spelled); only the
+        // lemma gets the འ reattached.
+        let mut tokens = vec![Token::with_text("བཀྲ་ཁས".to_string(), 0, 18, ChunkType::Text)];
+        tokens[0].syls = vec!["བཀྲ".to_string(), "ཁས".to_string()];
+        tokens[0].affixation = Some(crate::token::AffixationInfo { len: 1, aa: true });
+        let original_text = tokens[0].text.clone();
+
+        split_affixed(&mut tokens);
+
+        assert_eq!(tokens.len(), 2);
+        assert_eq!(tokens[0].syls, vec!["བཀྲ".to_string(), "ཁ".to_string()]);
+        assert_eq!(tokens[0].lemma.as_deref(), Some("བཀྲ་ཁའ#ས"));
+
+        unsplit_affixed(&mut tokens);
+
+        assert_eq!(tokens.len(), 1);
+        assert_eq!(tokens[0].text, original_text);
+        assert_eq!(tokens[0].lemma.as_deref(), Some("བཀྲ་ཁའ་ས"));
+    }
+
     #[test]
     fn test_merge_dagdra() {
         let mut tokens = vec![
@@ -219,6 +691,163 @@ mod tests {
         assert_eq!(tokens[0].syls.len(), 3);
     }
 
+    #[test]
+    fn test_modifier_pipeline_matches_apply_all_modifiers() {
+        let mut via_pipeline = vec![
+            Token::with_text("བཀྲ་ཤིས་".to_string(), 0, 18, ChunkType::Text),
+            Token::with_text("པ་".to_string(), 18, 6, ChunkType::Text),
+        ];
+        via_pipeline[0].syls = vec!["བཀྲ".to_string(), "ཤིས".to_string()];
+        via_pipeline[1].syls = vec!["པ".to_string()];
+        let mut via_apply_all = via_pipeline.clone();
+
+        ModifierPipeline::default_pipeline(false).apply(&mut via_pipeline);
+        apply_all_modifiers(&mut via_apply_all, false);
+
+        assert_eq!(via_pipeline.len(), via_apply_all.len());
+        assert_eq!(via_pipeline[0].lemma, via_apply_all[0].lemma);
+    }
+
+    #[test]
+    fn test_modifier_pipeline_runs_custom_transform_in_order() {
+        struct UppercasePos;
+        impl TokenTransform for UppercasePos {
+            fn apply(&self, tokens: &mut Vec<Token>) {
+                for token in tokens.iter_mut() {
+                    token.pos = token.pos.as_ref().map(|p| p.to_uppercase());
+                }
+            }
+        }
+
+        let mut tokens = vec![Token::with_text("ཀ".to_string(), 0, 3, ChunkType::Text)];
+        tokens[0].pos = Some("noun".to_string());
+
+        ModifierPipeline::new().with(UppercasePos).apply(&mut tokens);
+
+        assert_eq!(tokens[0].pos.as_deref(), Some("NOUN"));
+    }
+
+    #[test]
+    fn test_merge_dagdra_with_lexicon_merges_attested_word() {
+        let mut builder = crate::trie::TrieBuilder::new();
+        builder.load_tsv("བཀྲ་ཤིས་པ\tNOUN\t\t\t1000");
+        let trie = builder.build();
+
+        let mut tokens = vec![
+            Token::with_text("བཀྲ་ཤིས་".to_string(), 0, 18, ChunkType::Text),
+            Token::with_text("པ་".to_string(), 18, 6, ChunkType::Text),
+        ];
+        tokens[0].syls = vec!["བཀྲ".to_string(), "ཤིས".to_string()];
+        tokens[1].syls = vec!["པ".to_string()];
+
+        merge_dagdra_with_lexicon(&mut tokens, &trie);
+
+        assert_eq!(tokens.len(), 1);
+        assert!(tokens[0].has_merged_dagdra);
+    }
+
+    #[test]
+    fn test_merge_dagdra_with_lexicon_leaves_unattested_word_split() {
+        let trie = crate::trie::TrieBuilder::new().build();
+
+        let mut tokens = vec![
+            Token::with_text("བཀྲ་ཤིས་".to_string(), 0, 18, ChunkType::Text),
+            Token::with_text("པ་".to_string(), 18, 6, ChunkType::Text),
+        ];
+        tokens[0].syls = vec!["བཀྲ".to_string(), "ཤིས".to_string()];
+        tokens[1].syls = vec!["པ".to_string()];
+
+        merge_dagdra_with_lexicon(&mut tokens, &trie);
+
+        assert_eq!(tokens.len(), 2);
+    }
+
+    #[test]
+    fn test_disambiguate_senses_prefers_transition_consistent_pos() {
+        // A token with two candidate senses, tied on frequency, sandwiched
+        // between fixed-POS neighbors that only a VERB reading bigrams with.
+        let mut tokens = vec![
+            Token::with_text("ངས".to_string(), 0, 6, ChunkType::Text),
+            Token::with_text("བྱས".to_string(), 6, 9, ChunkType::Text),
+            Token::with_text("སོང་".to_string(), 15, 12, ChunkType::Text),
+        ];
+        tokens[0].pos = Some("PRON".to_string());
+        tokens[1].senses = vec![
+            Sense { pos: Some("NOUN".to_string()), freq: Some(100), ..Default::default() },
+            Sense { pos: Some("VERB".to_string()), freq: Some(100), ..Default::default() },
+        ];
+        tokens[2].pos = Some("VERB".to_string());
+
+        let mut transitions = HashMap::new();
+        transitions.insert(("PRON".to_string(), "NOUN".to_string()), 0.01);
+        transitions.insert(("PRON".to_string(), "VERB".to_string()), 0.9);
+        transitions.insert(("NOUN".to_string(), "VERB".to_string()), 0.01);
+        transitions.insert(("VERB".to_string(), "VERB".to_string()), 0.9);
+
+        disambiguate_senses(&mut tokens, &transitions);
+
+        assert_eq!(tokens[1].pos.as_deref(), Some("VERB"));
+    }
+
+    #[test]
+    fn test_disambiguate_senses_leaves_senseless_tokens_untouched() {
+        let mut tokens = vec![Token::with_text("།".to_string(), 0, 3, ChunkType::Punct)];
+        tokens[0].pos = Some("PUNCT".to_string());
+
+        disambiguate_senses(&mut tokens, &HashMap::new());
+
+        assert_eq!(tokens[0].pos.as_deref(), Some("PUNCT"));
+    }
+
+    #[test]
+    fn test_normalize_spelling_corrects_close_misspelling() {
+        let mut builder = crate::trie::TrieBuilder::new();
+        builder.load_tsv("བདེ་ལེགས\tNOUN\t\t\t1000");
+        let trie = builder.build();
+
+        let mut tokens = vec![Token::with_text("བདེ་ལིགས་".to_string(), 0, 21, ChunkType::Text)];
+        tokens[0].syls = vec!["བདེ".to_string(), "ལིགས".to_string()];
+
+        normalize_spelling(&mut tokens, &trie, 1);
+
+        assert_eq!(tokens[0].lemma.as_deref(), Some("བདེ་ལེགས"));
+        assert_eq!(tokens[0].text, "བདེ་ལིགས་");
+        assert!(!tokens[0].suggestions.is_empty());
+        assert!(tokens[0].spelling_confidence.unwrap() > 0.0);
+    }
+
+    #[test]
+    fn test_normalize_spelling_leaves_attested_word_untouched() {
+        let mut builder = crate::trie::TrieBuilder::new();
+        builder.load_tsv("བདེ་ལེགས\tNOUN\t\t\t1000");
+        let trie = builder.build();
+
+        let mut tokens = vec![Token::with_text("བདེ་ལེགས་".to_string(), 0, 21, ChunkType::Text)];
+        tokens[0].syls = vec!["བདེ".to_string(), "ལེགས".to_string()];
+
+        normalize_spelling(&mut tokens, &trie, 1);
+
+        assert!(tokens[0].lemma.is_none());
+        assert!(tokens[0].spelling_confidence.is_none());
+    }
+
+    #[test]
+    fn test_normalize_spelling_pipeline_stage_is_opt_in() {
+        let mut builder = crate::trie::TrieBuilder::new();
+        builder.load_tsv("བདེ་ལེགས\tNOUN\t\t\t1000");
+        let trie = builder.build();
+
+        let mut tokens = vec![Token::with_text("བདེ་ལིགས་".to_string(), 0, 21, ChunkType::Text)];
+        tokens[0].syls = vec!["བདེ".to_string(), "ལིགས".to_string()];
+
+        ModifierPipeline::default_pipeline(false).apply(&mut tokens);
+        assert!(tokens[0].lemma.is_some());
+        assert_ne!(tokens[0].lemma.as_deref(), Some("བདེ་ལེགས"));
+
+        ModifierPipeline::new().with(NormalizeSpelling::new(trie, 1)).apply(&mut tokens);
+        assert_eq!(tokens[0].lemma.as_deref(), Some("བདེ་ལེགས"));
+    }
+
     #[test]
     fn test_generate_default_lemmas() {
         let mut tokens = vec![
@@ -6,14 +6,25 @@ use std::sync::Arc;
 
 use pyo3::prelude::*;
 use pyo3::types::{PyDict, PyList};
+use rayon::prelude::*;
 
 use crate::chunker::Chunker;
 use crate::token::{ChunkType, Token as RustToken};
 use crate::tokenizer::{SimpleTokenizer as RustSimpleTokenizer, Tokenizer as RustTokenizer};
+use crate::dialect_pack;
 use crate::trie::{Trie, TrieBuilder, WordData};
 
-#[cfg(feature = "download")]
-use crate::dialect_pack;
+/// Attach ranked spelling-correction candidates to every unknown (`NO_POS`)
+/// token in `tokens`, via a bounded edit-distance search over `trie`.
+fn attach_suggestions(tokens: &mut [RustToken], trie: &Trie, max_dist: usize, max_suggestions: usize) {
+    for token in tokens {
+        if token.pos.as_deref() == Some("NO_POS") && !token.syls.is_empty() {
+            let mut suggestions = trie.fuzzy_lookup(&token.syls, max_dist);
+            suggestions.truncate(max_suggestions);
+            token.suggestions = suggestions;
+        }
+    }
+}
 
 /// A Python-compatible Token class
 #[pyclass(name = "Token")]
@@ -41,6 +52,8 @@ pub struct PyToken {
     pub is_affix_host: bool,
     #[pyo3(get)]
     pub is_skrt: bool,
+    #[pyo3(get)]
+    pub suggestions: Vec<String>,
 }
 
 impl From<RustToken> for PyToken {
@@ -57,6 +70,7 @@ impl From<RustToken> for PyToken {
             is_affix: t.is_affix,
             is_affix_host: t.is_affix_host,
             is_skrt: t.is_skrt,
+            suggestions: t.suggestions.into_iter().map(|s| s.syls.join("་")).collect(),
         }
     }
 }
@@ -111,6 +125,7 @@ impl PyToken {
         dict.set_item("is_affix", self.is_affix)?;
         dict.set_item("is_affix_host", self.is_affix_host)?;
         dict.set_item("is_skrt", self.is_skrt)?;
+        dict.set_item("suggestions", &self.suggestions)?;
         Ok(dict)
     }
 }
@@ -130,47 +145,50 @@ impl PyToken {
 pub struct PyWordTokenizer {
     /// Shared trie reference - avoids expensive clones on each tokenize() call
     trie: Arc<Trie>,
+    /// The loaded pack's `manifest.json`, if it had one
+    manifest: Option<dialect_pack::PackManifest>,
 }
 
 #[pymethods]
 impl PyWordTokenizer {
     /// Create a new WordTokenizer.
-    /// 
+    ///
     /// Args:
-    ///     dialect_name: Name of the dialect pack to use (default: "general")
-    ///     base_path: Base path for dialect packs (default: ~/Documents/botok-rs/dialect_packs/)
-    ///     auto_download: Whether to automatically download the dialect pack (default: True)
-    /// 
-    /// If auto_download is True and the dialect pack is not found locally,
+    ///     config: Dialect pack name, or a local path to a dialect pack directory
+    ///         (default: "general")
+    ///     base_path: Base path for dialect packs when `config` names a pack
+    ///         (default: ~/Documents/botok-rs/dialect_packs/)
+    ///     custom_path: Local overlay directory layered on top of the resolved pack -
+    ///         its own `words`, `words_skrt`, `remove`, and `adjustments` sections
+    ///         (optional; see `Config` for the section layering order)
+    ///     auto_download: Whether to automatically download the dialect pack if it's
+    ///         not found locally (default: True)
+    ///
+    /// If auto_download is True and the resolved dialect pack is not found locally,
     /// it will be downloaded from GitHub automatically.
     #[new]
-    #[pyo3(signature = (dialect_name=None, base_path=None, auto_download=true))]
-    fn new(dialect_name: Option<&str>, base_path: Option<&str>, auto_download: bool) -> PyResult<Self> {
-        let mut trie = Trie::new();
-        
+    #[pyo3(signature = (config=None, base_path=None, custom_path=None, auto_download=true))]
+    fn new(config: Option<&str>, base_path: Option<&str>, custom_path: Option<&str>, auto_download: bool) -> PyResult<Self> {
+        let dialect = config.unwrap_or(dialect_pack::DEFAULT_DIALECT_PACK);
+        let base = base_path.map(std::path::Path::new);
+
         #[cfg(feature = "download")]
         if auto_download {
-            let dialect = dialect_name.unwrap_or(dialect_pack::DEFAULT_DIALECT_PACK);
-            let base = base_path.map(std::path::Path::new);
-            
-            // Download dialect pack if needed
-            let pack_path = dialect_pack::get_dialect_pack(dialect, base)
+            dialect_pack::get_dialect_pack(dialect, base)
                 .map_err(|e| PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(e.to_string()))?;
-            
-            // Load all dictionary files
-            let dict_files = dialect_pack::list_dictionary_files(&pack_path)
-                .map_err(|e| PyErr::new::<pyo3::exceptions::PyIOError, _>(e.to_string()))?;
-            
-            let mut builder = TrieBuilder::new();
-            for file in dict_files {
-                if let Ok(content) = std::fs::read_to_string(&file) {
-                    builder.load_tsv(&content);
-                }
-            }
-            trie = builder.build();
         }
-        
-        Ok(PyWordTokenizer { trie: Arc::new(trie) })
+
+        let mut profile = dialect_pack::Config::new(dialect, base);
+        if let Some(custom) = custom_path {
+            profile = profile.with_custom_dir(custom);
+        }
+
+        let manifest = profile.manifest();
+        let trie = profile
+            .build_trie()
+            .map_err(|e| PyErr::new::<pyo3::exceptions::PyIOError, _>(e.to_string()))?;
+
+        Ok(PyWordTokenizer { trie: Arc::new(trie), manifest })
     }
 
     /// Load words from a TSV string
@@ -196,34 +214,50 @@ impl PyWordTokenizer {
     }
 
     /// Load a dialect pack by name
-    /// 
+    ///
     /// Args:
     ///     dialect_name: Name of the dialect pack (e.g., "general")
     ///     base_path: Base path for dialect packs (optional)
-    /// 
-    /// This will download the dialect pack if not already present.
+    ///
+    /// This will download the dialect pack if not already present. On
+    /// subsequent calls (with the `mmap` feature), the pack's compiled
+    /// trie is loaded from its on-disk cache instead of re-parsing TSV.
     #[cfg(feature = "download")]
     #[pyo3(signature = (dialect_name, base_path=None))]
     fn load_dialect_pack(&mut self, dialect_name: &str, base_path: Option<&str>) -> PyResult<()> {
         let base = base_path.map(std::path::Path::new);
-        
-        let pack_path = dialect_pack::get_dialect_pack(dialect_name, base)
+
+        dialect_pack::get_dialect_pack(dialect_name, base)
             .map_err(|e| PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(e.to_string()))?;
-        
-        let dict_files = dialect_pack::list_dictionary_files(&pack_path)
+
+        let config = dialect_pack::Config::new(dialect_name, base);
+        let manifest = config.manifest();
+        let trie = config
+            .build_trie()
             .map_err(|e| PyErr::new::<pyo3::exceptions::PyIOError, _>(e.to_string()))?;
-        
-        let mut builder = TrieBuilder::new();
-        for file in dict_files {
-            if let Ok(content) = std::fs::read_to_string(&file) {
-                builder.load_tsv(&content);
-            }
-        }
-        self.trie = Arc::new(builder.build());
-        
+        self.trie = Arc::new(trie);
+        self.manifest = manifest;
+
         Ok(())
     }
 
+    /// Get metadata from the loaded pack's `manifest.json`, or `None` if
+    /// the pack doesn't have one.
+    ///
+    /// Returns:
+    ///     A dict with `name`, `version`, `sections`, and
+    ///     `min_crate_version` keys, or `None`
+    fn pack_info<'py>(&self, py: Python<'py>) -> PyResult<Option<Bound<'py, PyDict>>> {
+        let Some(manifest) = &self.manifest else { return Ok(None) };
+
+        let dict = PyDict::new_bound(py);
+        dict.set_item("name", &manifest.name)?;
+        dict.set_item("version", &manifest.version)?;
+        dict.set_item("sections", &manifest.sections)?;
+        dict.set_item("min_crate_version", &manifest.min_crate_version)?;
+        Ok(Some(dict))
+    }
+
     /// Add a single word to the dictionary
     /// 
     /// Args:
@@ -250,15 +284,105 @@ impl PyWordTokenizer {
     /// Args:
     ///     text: The Tibetan text to tokenize
     ///     split_affixes: Whether to split affixed particles (default: True)
-    /// 
+    ///     normalize: Whether to NFC-normalize text before chunking (default: True) -
+    ///         disable only if text is already known to be normalized
+    ///     suggest_unknown: Whether to attach spelling-correction candidates to
+    ///         unknown (bare-syllable) tokens, as `Token.suggestions` (default: False)
+    ///     max_dist: Maximum syllable-level edit distance for suggestions (default: 2)
+    ///     max_suggestions: Maximum number of suggestions attached per token (default: 3)
+    ///
     /// Returns:
     ///     List of Token objects
-    #[pyo3(signature = (text, split_affixes=true))]
-    fn tokenize(&self, text: &str, split_affixes: bool) -> Vec<PyToken> {
+    #[pyo3(signature = (text, split_affixes=true, normalize=true, suggest_unknown=false, max_dist=2, max_suggestions=3))]
+    fn tokenize(
+        &self,
+        text: &str,
+        split_affixes: bool,
+        normalize: bool,
+        suggest_unknown: bool,
+        max_dist: usize,
+        max_suggestions: usize,
+    ) -> Vec<PyToken> {
         // Use Arc::clone for cheap reference counting instead of cloning the whole trie
+        let tokenizer = RustTokenizer::with_arc(Arc::clone(&self.trie));
+        let mut tokens = tokenizer.tokenize_with_full_options(text, split_affixes, false, false, normalize);
+
+        if suggest_unknown {
+            attach_suggestions(&mut tokens, &self.trie, max_dist, max_suggestions);
+        }
+
+        tokens.into_iter().map(PyToken::from).collect()
+    }
+
+    /// Suggest spelling corrections for a word, ranked by edit distance then
+    /// frequency.
+    ///
+    /// Walks the dictionary trie with a bounded edit-distance search (see
+    /// `Trie::fuzzy_lookup`) rather than requiring a separate spelling
+    /// dictionary.
+    ///
+    /// Args:
+    ///     word: The (possibly misspelled) word to look up, syllables
+    ///         separated by a tsek
+    ///     max_dist: Maximum syllable-level edit distance to consider (default: 1)
+    ///
+    /// Returns:
+    ///     Candidate dictionary forms, nearest first
+    #[pyo3(signature = (word, max_dist=1))]
+    fn suggest(&self, word: &str, max_dist: usize) -> Vec<String> {
+        let syls: Vec<String> = word.split('་').filter(|s| !s.is_empty()).map(String::from).collect();
+        self.trie
+            .fuzzy_lookup(&syls, max_dist)
+            .into_iter()
+            .map(|s| s.syls.join("་"))
+            .collect()
+    }
+
+    /// Tokenize many documents in one call, across a rayon thread pool.
+    ///
+    /// The trie is cheap to share (an `Arc`), so each document is tokenized
+    /// on a worker thread with its own Rust `Tokenizer` handle while the GIL
+    /// is released, giving near-linear speedups over calling `tokenize` in
+    /// a Python loop - without resorting to Python-side multiprocessing.
+    ///
+    /// Args:
+    ///     texts: The documents to tokenize
+    ///     split_affixes: Whether to split affixed particles (default: True)
+    ///
+    /// Returns:
+    ///     One list of Token objects per input document, in the same order
+    #[pyo3(signature = (texts, split_affixes=true))]
+    fn tokenize_batch(&self, py: Python<'_>, texts: Vec<String>, split_affixes: bool) -> Vec<Vec<PyToken>> {
+        let trie = Arc::clone(&self.trie);
+        let results: Vec<Vec<RustToken>> = py.allow_threads(|| {
+            texts
+                .par_iter()
+                .map(|text| RustTokenizer::with_arc(Arc::clone(&trie)).tokenize_with_options(text, split_affixes))
+                .collect()
+        });
+
+        results
+            .into_iter()
+            .map(|tokens| tokens.into_iter().map(PyToken::from).collect())
+            .collect()
+    }
+
+    /// Tokenize a string with configurable options
+    ///
+    /// Args:
+    ///     text: The Tibetan text to tokenize
+    ///     split_affixes: Whether to split affixed particles (default: True)
+    ///     spaces_as_punct: Whether to treat spaces as punctuation tokens (default: False)
+    ///     normalize: Whether to NFC-normalize text before chunking (default: True) -
+    ///         disable only if text is already known to be normalized
+    ///
+    /// Returns:
+    ///     List of Token objects
+    #[pyo3(signature = (text, split_affixes=true, spaces_as_punct=false, normalize=true))]
+    fn tokenize_with_options(&self, text: &str, split_affixes: bool, spaces_as_punct: bool, normalize: bool) -> Vec<PyToken> {
         let tokenizer = RustTokenizer::with_arc(Arc::clone(&self.trie));
         tokenizer
-            .tokenize_with_options(text, split_affixes)
+            .tokenize_with_full_options(text, split_affixes, spaces_as_punct, false, normalize)
             .into_iter()
             .map(PyToken::from)
             .collect()
@@ -274,6 +398,52 @@ impl PyWordTokenizer {
     }
 }
 
+/// A dialect-pack profile: a base pack plus an optional local overlay
+/// directory, resolved into named sections and layered in a fixed order.
+///
+/// Example:
+///     >>> from botok_rs import Config
+///     >>> config = Config("general", custom_path="./my_overlay")
+///     >>> trie = config.build_trie()
+#[pyclass(name = "Config")]
+pub struct PyConfig {
+    config: dialect_pack::Config,
+}
+
+#[pymethods]
+impl PyConfig {
+    /// Create a new Config.
+    ///
+    /// Args:
+    ///     dialect_or_path: Dialect pack name, or a local path to a dialect pack directory
+    ///     base_path: Base path for dialect packs when `dialect_or_path` names a pack (optional)
+    ///     custom_path: Local overlay directory - its own `words`, `words_skrt`, `remove`,
+    ///         and `adjustments` sections, layered on top of the base pack (optional)
+    #[new]
+    #[pyo3(signature = (dialect_or_path, base_path=None, custom_path=None))]
+    fn new(dialect_or_path: &str, base_path: Option<&str>, custom_path: Option<&str>) -> Self {
+        let base = base_path.map(std::path::Path::new);
+        let mut config = dialect_pack::Config::new(dialect_or_path, base);
+        if let Some(custom) = custom_path {
+            config = config.with_custom_dir(custom);
+        }
+        PyConfig { config }
+    }
+
+    /// Resolve every section and build a Trie from them, in layering order.
+    fn build_trie(&self) -> PyResult<PyTrie> {
+        let trie = self
+            .config
+            .build_trie()
+            .map_err(|e| PyErr::new::<pyo3::exceptions::PyIOError, _>(e.to_string()))?;
+        Ok(PyTrie { trie })
+    }
+
+    fn __repr__(&self) -> String {
+        format!("Config({:?})", self.config.base_path())
+    }
+}
+
 /// Trie data structure wrapper for Python
 /// 
 /// This wraps the internal Trie for advanced usage.
@@ -295,6 +465,22 @@ impl PyTrie {
         self.trie.has_word(&syls)
     }
 
+    /// Save this trie to a binary cache file, loadable with [`PyTrie.load`].
+    #[cfg(feature = "mmap")]
+    fn save(&self, path: &str) -> PyResult<()> {
+        self.trie
+            .save_cache(path)
+            .map_err(|e| PyErr::new::<pyo3::exceptions::PyIOError, _>(e.to_string()))
+    }
+
+    /// Load a trie previously written by [`PyTrie.save`].
+    #[staticmethod]
+    #[cfg(feature = "mmap")]
+    fn load(path: &str) -> PyResult<Self> {
+        let trie = Trie::load(path).map_err(|e| PyErr::new::<pyo3::exceptions::PyIOError, _>(e.to_string()))?;
+        Ok(PyTrie { trie })
+    }
+
     fn __repr__(&self) -> String {
         format!("Trie(words={})", self.trie.len())
     }
@@ -453,6 +639,9 @@ fn chunk<'py>(py: Python<'py>, text: &str) -> PyResult<Bound<'py, PyList>> {
                 ChunkType::Sym => "SYM",
                 ChunkType::Latin => "LATIN",
                 ChunkType::Cjk => "CJK",
+                ChunkType::Space => "SPACE",
+                ChunkType::HeadMark => "HEAD_MARK",
+                ChunkType::ClosingMark => "CLOSING_MARK",
                 ChunkType::Other => "OTHER",
             },
             &text[chunk.start..chunk.start + chunk.len],
@@ -479,17 +668,17 @@ fn get_syls(text: &str) -> Vec<String> {
 
     chunks
         .into_iter()
-        .filter_map(|c| c.syl)
+        .filter_map(|c| c.syl.map(|s| s.into_owned()))
         .collect()
 }
 
 /// Tokenize text using simple syllable tokenization
-/// 
+///
 /// This is a convenience function equivalent to SimpleTokenizer.tokenize()
-/// 
+///
 /// Args:
 ///     text: The Tibetan text to tokenize
-/// 
+///
 /// Returns:
 ///     List of Token objects
 #[pyfunction]
@@ -497,6 +686,22 @@ fn tokenize_simple(text: &str) -> Vec<PyToken> {
     PySimpleTokenizer::tokenize(text)
 }
 
+/// NFC-normalize Tibetan text
+///
+/// Canonicalizes precomposed/decomposed form mixing (e.g. stacked vowels)
+/// the same way WordTokenizer.tokenize does internally. Useful before
+/// diffing or indexing text without going through a tokenizer.
+///
+/// Args:
+///     text: The text to normalize
+///
+/// Returns:
+///     The NFC-normalized text
+#[pyfunction]
+fn normalize_tibetan(text: &str) -> String {
+    crate::tokenizer::normalize_tibetan(text)
+}
+
 /// Download a dialect pack from GitHub
 /// 
 /// Args:
@@ -575,9 +780,11 @@ fn botok_rs(m: &Bound<'_, PyModule>) -> PyResult<()> {
     m.add_class::<PySimpleTokenizer>()?;
     m.add_class::<PyTrie>()?;
     m.add_class::<PyTrieBuilder>()?;
+    m.add_class::<PyConfig>()?;
     m.add_function(wrap_pyfunction!(chunk, m)?)?;
     m.add_function(wrap_pyfunction!(get_syls, m)?)?;
     m.add_function(wrap_pyfunction!(tokenize_simple, m)?)?;
+    m.add_function(wrap_pyfunction!(normalize_tibetan, m)?)?;
     
     // Dialect pack functions (only available with download feature)
     #[cfg(feature = "download")]
@@ -0,0 +1,310 @@
+//! Typo-tolerant phrase search over tokenized sentences.
+//!
+//! This module builds on [`crate::sentence::Sentence`] and
+//! [`crate::sentence::Paragraph`] to locate a query phrase inside a token
+//! stream even when the input has minor spelling mistakes, which the
+//! crate's dictionary-based tokenization alone cannot provide.
+
+use crate::sentence::{Paragraph, Sentence};
+use crate::token::Token;
+
+/// Compute the Levenshtein (edit) distance between two strings, operating
+/// on Unicode scalar values rather than bytes.
+fn levenshtein(a: &str, b: &str) -> usize {
+    let a: Vec<char> = a.chars().collect();
+    let b: Vec<char> = b.chars().collect();
+
+    if a.is_empty() {
+        return b.len();
+    }
+    if b.is_empty() {
+        return a.len();
+    }
+
+    let mut prev: Vec<usize> = (0..=b.len()).collect();
+    let mut curr = vec![0; b.len() + 1];
+
+    for i in 1..=a.len() {
+        curr[0] = i;
+        for j in 1..=b.len() {
+            let cost = if a[i - 1] == b[j - 1] { 0 } else { 1 };
+            curr[j] = (prev[j] + 1).min(curr[j - 1] + 1).min(prev[j - 1] + cost);
+        }
+        std::mem::swap(&mut prev, &mut curr);
+    }
+
+    prev[b.len()]
+}
+
+/// Pick a default maximum edit distance based on word length: short words
+/// tolerate no typos (an edit could turn one real word into another),
+/// medium words tolerate one, and longer words tolerate two.
+fn default_max_distance(char_len: usize) -> usize {
+    if char_len <= 3 {
+        0
+    } else if char_len <= 6 {
+        1
+    } else {
+        2
+    }
+}
+
+/// A single query word compiled into a typo-tolerant matcher.
+#[derive(Debug, Clone)]
+struct WordMatcher {
+    word: String,
+    max_distance: usize,
+    prefix: bool,
+}
+
+impl WordMatcher {
+    fn new(word: &str) -> Self {
+        let char_len = word.chars().count();
+        WordMatcher {
+            word: word.to_string(),
+            max_distance: default_max_distance(char_len),
+            prefix: false,
+        }
+    }
+
+    /// Check a candidate token's cleaned text against this matcher,
+    /// returning the edit distance if it is within tolerance.
+    fn distance_to(&self, candidate: &str) -> Option<usize> {
+        let candidate = candidate.trim_end_matches('་');
+
+        if self.prefix {
+            let query_len = self.word.chars().count();
+            let truncated: String = candidate.chars().take(query_len).collect();
+            let distance = levenshtein(&self.word, &truncated);
+            if distance <= self.max_distance {
+                return Some(distance);
+            }
+            return None;
+        }
+
+        let distance = levenshtein(&self.word, candidate);
+        if distance <= self.max_distance {
+            Some(distance)
+        } else {
+            None
+        }
+    }
+}
+
+/// A query phrase split into per-word matchers, to be searched for inside
+/// a sentence's token stream.
+#[derive(Debug, Clone)]
+pub struct PhraseQuery {
+    matchers: Vec<WordMatcher>,
+}
+
+impl PhraseQuery {
+    /// Build a query from a whitespace-separated phrase, one query word per
+    /// token, e.g. `"བཀྲ་ཤིས བདེ་ལེགས"` or `"bkra shis"`. A tsek stays
+    /// inside its word.
+    pub fn new(phrase: &str) -> Self {
+        let matchers = phrase
+            .split(char::is_whitespace)
+            .filter(|w| !w.is_empty())
+            .map(WordMatcher::new)
+            .collect();
+
+        PhraseQuery { matchers }
+    }
+
+    /// Override the maximum edit distance for every word in this query.
+    pub fn with_max_distance(mut self, max_distance: usize) -> Self {
+        for matcher in &mut self.matchers {
+            matcher.max_distance = max_distance;
+        }
+        self
+    }
+
+    /// Allow each query word to match a prefix of a longer candidate token.
+    pub fn with_prefix_match(mut self, allow_prefix: bool) -> Self {
+        for matcher in &mut self.matchers {
+            matcher.prefix = allow_prefix;
+        }
+        self
+    }
+
+    /// Number of words in this query, used to prioritize longer phrases.
+    fn word_count(&self) -> usize {
+        self.matchers.len()
+    }
+
+    /// Try to match this query starting at `start` in `tokens`, returning
+    /// the summed edit distance on success.
+    fn match_at(&self, tokens: &[Token], start: usize) -> Option<usize> {
+        if start + self.matchers.len() > tokens.len() {
+            return None;
+        }
+
+        let mut total_distance = 0;
+        for (matcher, token) in self.matchers.iter().zip(&tokens[start..]) {
+            let distance = matcher.distance_to(&token.text_cleaned())?;
+            total_distance += distance;
+        }
+
+        Some(total_distance)
+    }
+}
+
+/// A highlightable match: a contiguous token span within a sentence.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SearchMatch {
+    /// Index of the matching sentence within the searched slice/paragraph
+    pub sentence_idx: usize,
+    /// Index of the first matched token within the sentence
+    pub start_token: usize,
+    /// Index of the last matched token within the sentence (inclusive)
+    pub end_token: usize,
+    /// Total edit distance summed across the matched words
+    pub distance: usize,
+}
+
+/// Searches one or more [`PhraseQuery`]s over tokenized sentences,
+/// preferring the longest matched region when candidate matches overlap.
+pub struct PhraseSearcher {
+    queries: Vec<PhraseQuery>,
+}
+
+impl PhraseSearcher {
+    /// Build a searcher from a set of queries, longest phrase first so
+    /// overlap resolution favors the fullest match.
+    pub fn new(mut queries: Vec<PhraseQuery>) -> Self {
+        queries.sort_by(|a, b| b.word_count().cmp(&a.word_count()));
+        PhraseSearcher { queries }
+    }
+
+    /// Search a single sentence, returning non-overlapping matches ordered
+    /// by token position.
+    pub fn search_sentence(&self, sentence_idx: usize, sentence: &Sentence) -> Vec<SearchMatch> {
+        let tokens = &sentence.tokens;
+        let mut matches: Vec<SearchMatch> = Vec::new();
+        let mut claimed = vec![false; tokens.len()];
+
+        for query in &self.queries {
+            if query.word_count() == 0 {
+                continue;
+            }
+
+            for start in 0..tokens.len() {
+                let end = start + query.word_count() - 1;
+                if end >= tokens.len() || claimed[start..=end].iter().any(|&c| c) {
+                    continue;
+                }
+
+                if let Some(distance) = query.match_at(tokens, start) {
+                    for slot in claimed.iter_mut().take(end + 1).skip(start) {
+                        *slot = true;
+                    }
+                    matches.push(SearchMatch {
+                        sentence_idx,
+                        start_token: start,
+                        end_token: end,
+                        distance,
+                    });
+                }
+            }
+        }
+
+        matches.sort_by_key(|m| m.start_token);
+        matches
+    }
+
+    /// Search every sentence in a paragraph.
+    pub fn search_paragraph(&self, paragraph: &Paragraph) -> Vec<SearchMatch> {
+        paragraph
+            .sentences
+            .iter()
+            .enumerate()
+            .flat_map(|(idx, sentence)| self.search_sentence(idx, sentence))
+            .collect()
+    }
+
+    /// Search a slice of sentences.
+    pub fn search_sentences(&self, sentences: &[Sentence]) -> Vec<SearchMatch> {
+        sentences
+            .iter()
+            .enumerate()
+            .flat_map(|(idx, sentence)| self.search_sentence(idx, sentence))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::token::ChunkType;
+
+    fn make_token(text: &str) -> Token {
+        let mut token = Token::with_text(text.to_string(), 0, text.len(), ChunkType::Text);
+        token.syls = text.split('་').filter(|s| !s.is_empty()).map(|s| s.to_string()).collect();
+        token
+    }
+
+    fn make_sentence(words: &[&str]) -> Sentence {
+        Sentence {
+            tokens: words.iter().map(|w| make_token(w)).collect(),
+            word_count: words.len(),
+            start_idx: 0,
+            end_idx: words.len(),
+        }
+    }
+
+    #[test]
+    fn test_exact_phrase_match() {
+        let sentence = make_sentence(&["བཀྲ་ཤིས", "བདེ་ལེགས", "ཡིན"]);
+        let searcher = PhraseSearcher::new(vec![PhraseQuery::new("བཀྲ་ཤིས")]);
+
+        let matches = searcher.search_sentence(0, &sentence);
+
+        assert_eq!(matches.len(), 1);
+        assert_eq!(matches[0].start_token, 0);
+        assert_eq!(matches[0].end_token, 0);
+        assert_eq!(matches[0].distance, 0);
+    }
+
+    #[test]
+    fn test_typo_tolerant_match() {
+        // "བདེ་ལེགས" (8 chars) allows up to 2 edits by default.
+        let sentence = make_sentence(&["བདེ་ལེགསན"]);
+        let searcher = PhraseSearcher::new(vec![PhraseQuery::new("བདེ་ལེགས")]);
+
+        let matches = searcher.search_sentence(0, &sentence);
+
+        assert_eq!(matches.len(), 1);
+        assert_eq!(matches[0].distance, 1);
+    }
+
+    #[test]
+    fn test_no_match_returns_empty() {
+        let sentence = make_sentence(&["ཀུན", "བཟང"]);
+        let searcher = PhraseSearcher::new(vec![PhraseQuery::new("བཀྲ་ཤིས")]);
+
+        assert!(searcher.search_sentence(0, &sentence).is_empty());
+    }
+
+    #[test]
+    fn test_longest_match_wins_on_overlap() {
+        let sentence = make_sentence(&["བཀྲ", "ཤིས", "ལེགས"]);
+        let searcher = PhraseSearcher::new(vec![
+            PhraseQuery::new("བཀྲ"),
+            PhraseQuery::new("བཀྲ ཤིས"),
+        ]);
+
+        let matches = searcher.search_sentence(0, &sentence);
+
+        assert_eq!(matches.len(), 1);
+        assert_eq!(matches[0].start_token, 0);
+        assert_eq!(matches[0].end_token, 1);
+    }
+
+    #[test]
+    fn test_levenshtein_basic() {
+        assert_eq!(levenshtein("kitten", "sitting"), 3);
+        assert_eq!(levenshtein("", "abc"), 3);
+        assert_eq!(levenshtein("abc", "abc"), 0);
+    }
+}
@@ -3,6 +3,9 @@
 //! This module provides higher-level tokenization that groups word tokens
 //! into sentences and paragraphs based on Tibetan punctuation and grammar.
 
+use std::cmp::Ordering;
+use std::collections::BinaryHeap;
+
 use crate::token::{ChunkType, Token};
 
 /// Ending particles that typically mark sentence boundaries
@@ -70,106 +73,647 @@ impl Paragraph {
     }
 }
 
-/// Tokenize a list of word tokens into sentences
+/// Configurable rule set for sentence/paragraph boundary detection.
+///
+/// The module-level [`ENDING_PARTICLES`], [`ENDING_VERBS`],
+/// [`CLAUSE_BOUNDARIES`], [`DAGDRA`] word lists and the paragraph/join
+/// thresholds used by the free-function [`sentence_tokenize`] are baked-in
+/// defaults. `SentenceTokenizer` holds the same rules as owned fields with a
+/// builder API, so callers working with classical vs. modern Tibetan (or a
+/// specialized corpus) can tune the behavior without recompiling.
+#[derive(Debug, Clone)]
+pub struct SentenceTokenizer {
+    ending_particles: Vec<String>,
+    ending_words: Vec<String>,
+    ending_verbs: Vec<String>,
+    clause_boundaries: Vec<String>,
+    dagdra: Vec<String>,
+    extra_boundary_markers: Vec<String>,
+    clause_boundary_terminates_sentence: bool,
+    paragraph_threshold: usize,
+    paragraph_max: usize,
+    join_threshold: usize,
+}
+
+impl Default for SentenceTokenizer {
+    fn default() -> Self {
+        SentenceTokenizer {
+            ending_particles: owned(ENDING_PARTICLES),
+            ending_words: owned(ENDING_WORDS),
+            ending_verbs: owned(ENDING_VERBS),
+            clause_boundaries: owned(CLAUSE_BOUNDARIES),
+            dagdra: owned(DAGDRA),
+            extra_boundary_markers: Vec::new(),
+            clause_boundary_terminates_sentence: true,
+            paragraph_threshold: 70,
+            paragraph_max: 150,
+            join_threshold: 4,
+        }
+    }
+}
+
+impl SentenceTokenizer {
+    /// Create a tokenizer with the default (hardcoded-equivalent) rule set.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Replace the ending-particle list (e.g. གོ་/ངོ་/དོ་...).
+    pub fn with_ending_particles(mut self, particles: Vec<String>) -> Self {
+        self.ending_particles = particles;
+        self
+    }
+
+    /// Replace the ending-verb list (e.g. ཡིན་/ཡོད་...).
+    pub fn with_ending_verbs(mut self, verbs: Vec<String>) -> Self {
+        self.ending_verbs = verbs;
+        self
+    }
+
+    /// Replace the clause-boundary particle list (e.g. སྟེ་/ཏེ་/ནས་...).
+    pub fn with_clause_boundaries(mut self, boundaries: Vec<String>) -> Self {
+        self.clause_boundaries = boundaries;
+        self
+    }
+
+    /// Set the paragraph word-count threshold and hard maximum.
+    pub fn with_paragraph_limits(mut self, threshold: usize, max: usize) -> Self {
+        self.paragraph_threshold = threshold;
+        self.paragraph_max = max;
+        self
+    }
+
+    /// Set the sentence length, in tokens, at or below which a verb-less
+    /// sentence is joined to an adjacent one.
+    pub fn with_join_threshold(mut self, threshold: usize) -> Self {
+        self.join_threshold = threshold;
+        self
+    }
+
+    /// Register extra syllables (beyond [`ENDING_WORDS`]) that can mark a
+    /// sentence boundary when immediately followed by punctuation.
+    pub fn with_extra_boundary_markers(mut self, markers: Vec<String>) -> Self {
+        self.extra_boundary_markers = markers;
+        self
+    }
+
+    /// Toggle whether a clause-boundary particle (e.g. ནས་/ལ་) is allowed
+    /// to terminate a sentence on its own when followed by punctuation.
+    pub fn allow_clause_boundary_terminator(mut self, allow: bool) -> Self {
+        self.clause_boundary_terminates_sentence = allow;
+        self
+    }
+
+    /// Tokenize a list of word tokens into sentences using this rule set.
+    pub fn sentence_tokenize(&self, tokens: &[Token]) -> Vec<Sentence> {
+        if tokens.is_empty() {
+            return vec![];
+        }
+
+        self.get_sentence_indices(tokens)
+            .into_iter()
+            .map(|(start, end)| {
+                let sentence_tokens = tokens[start..=end].to_vec();
+                let word_count = sentence_tokens
+                    .iter()
+                    .filter(|t| t.chunk_type == ChunkType::Text)
+                    .count();
+
+                Sentence {
+                    tokens: sentence_tokens,
+                    word_count,
+                    start_idx: start,
+                    end_idx: end,
+                }
+            })
+            .collect()
+    }
+
+    /// Tokenize a list of word tokens into paragraphs using this rule set.
+    pub fn paragraph_tokenize(&self, tokens: &[Token]) -> Vec<Paragraph> {
+        let sentences = self.sentence_tokenize(tokens);
+
+        if sentences.is_empty() {
+            return vec![];
+        }
+
+        let mut paragraphs: Vec<Paragraph> = Vec::new();
+        let mut current_sentences: Vec<Sentence> = Vec::new();
+        let mut current_word_count = 0;
+
+        for sentence in sentences {
+            let sentence_words = sentence.word_count;
+
+            if current_word_count + sentence_words > self.paragraph_max && !current_sentences.is_empty() {
+                paragraphs.push(Paragraph {
+                    sentences: std::mem::take(&mut current_sentences),
+                    word_count: current_word_count,
+                });
+                current_word_count = 0;
+            }
+
+            current_word_count += sentence_words;
+            current_sentences.push(sentence);
+
+            if current_word_count >= self.paragraph_threshold {
+                paragraphs.push(Paragraph {
+                    sentences: std::mem::take(&mut current_sentences),
+                    word_count: current_word_count,
+                });
+                current_word_count = 0;
+            }
+        }
+
+        if !current_sentences.is_empty() {
+            paragraphs.push(Paragraph {
+                sentences: current_sentences,
+                word_count: current_word_count,
+            });
+        }
+
+        paragraphs
+    }
+
+    fn get_sentence_indices(&self, tokens: &[Token]) -> Vec<(usize, usize)> {
+        if tokens.is_empty() {
+            return vec![];
+        }
+
+        let mut boundaries = find_boundaries(tokens, |t1, t2| self.is_ending_particle_and_punct(t1, t2));
+        boundaries = refine_boundaries(tokens, &boundaries, |t1, t2| self.is_clause_boundary_and_punct(t1, t2));
+        boundaries = refine_boundaries(tokens, &boundaries, |t1, t2| self.is_verb_and_punct(t1, t2));
+        boundaries =
+            refine_long_sentences(tokens, &boundaries, |t1, t2| self.is_verb_and_clause_boundary(t1, t2), 30);
+        self.join_no_verb_sentences(tokens, &boundaries)
+    }
+
+    fn join_no_verb_sentences(&self, tokens: &[Token], boundaries: &[(usize, usize)]) -> Vec<(usize, usize)> {
+        let mut result: Vec<(usize, usize)> = boundaries.to_vec();
+        let mut i = 0;
+
+        while i < result.len() {
+            let (start, end) = result[i];
+            let length = end - start + 1;
+
+            if length <= self.join_threshold {
+                let has_verb = tokens[start..=end]
+                    .iter()
+                    .any(|t| t.pos.as_deref() == Some("VERB") && !has_last_syl(t, &self.dagdra));
+
+                if !has_verb {
+                    if i + 1 < result.len() && has_last_syl(&tokens[end], &self.clause_boundaries) {
+                        result[i + 1].0 = start;
+                        result.remove(i);
+                        continue;
+                    } else if i > 0 {
+                        let prev_end = result[i - 1].1;
+                        if !has_last_syl(&tokens[prev_end], &self.ending_particles) {
+                            result[i - 1].1 = end;
+                            result.remove(i);
+                            continue;
+                        }
+                    }
+                }
+            }
+            i += 1;
+        }
+
+        result
+    }
+
+    fn is_ending_particle(&self, token: &Token) -> bool {
+        token.pos.as_deref() == Some("PART") && has_last_syl(token, &self.ending_particles)
+    }
+
+    fn is_ending_particle_and_punct(&self, token1: &Token, token2: &Token) -> bool {
+        self.is_ending_particle(token1) && is_sentence_final_punct(token2)
+    }
+
+    fn is_clause_boundary_and_punct(&self, token1: &Token, token2: &Token) -> bool {
+        let clause_hit = self.clause_boundary_terminates_sentence && has_last_syl(token1, &self.clause_boundaries);
+        (clause_hit
+            || has_last_syl(token1, &self.ending_words)
+            || has_last_syl(token1, &self.extra_boundary_markers))
+            && is_sentence_final_punct(token2)
+    }
+
+    fn is_verb_and_punct(&self, token1: &Token, token2: &Token) -> bool {
+        let is_verb = (token1.pos.as_deref() == Some("VERB") && !has_last_syl(token1, &self.dagdra))
+            || has_last_syl(token1, &self.ending_verbs);
+        is_verb && is_sentence_final_punct(token2)
+    }
+
+    fn is_verb_and_clause_boundary(&self, token1: &Token, token2: &Token) -> bool {
+        let is_verb = (token1.pos.as_deref() == Some("VERB") && !has_last_syl(token1, &self.dagdra))
+            || has_last_syl(token1, &self.ending_verbs);
+        is_verb && has_last_syl(token2, &self.clause_boundaries)
+    }
+}
+
+/// Convert a static `&[&str]` word list into owned `Vec<String>` for use as
+/// a [`SentenceTokenizer`] default.
+fn owned(words: &[&str]) -> Vec<String> {
+    words.iter().map(|s| s.to_string()).collect()
+}
+
+/// Tokenize a list of word tokens into sentences using the default rule set.
 pub fn sentence_tokenize(tokens: &[Token]) -> Vec<Sentence> {
+    SentenceTokenizer::default().sentence_tokenize(tokens)
+}
+
+/// Tokenize a list of word tokens into paragraphs using the default rule set.
+pub fn paragraph_tokenize(tokens: &[Token]) -> Vec<Paragraph> {
+    SentenceTokenizer::default().paragraph_tokenize(tokens)
+}
+
+/// A chunk of tokens sized for a fixed embedding window.
+///
+/// Unlike [`Paragraph`], a `WindowChunk` targets a word-count budget rather
+/// than the fixed 70/150 thresholds, and always breaks at the strongest
+/// available boundary so sentence/paragraph structure stays intact.
+#[derive(Debug, Clone)]
+pub struct WindowChunk {
+    /// The tokens making up this chunk
+    pub tokens: Vec<Token>,
+    /// Start index in the original token list
+    pub start_idx: usize,
+    /// End index in the original token list (inclusive)
+    pub end_idx: usize,
+    /// Number of word tokens in this chunk
+    pub word_count: usize,
+}
+
+/// Split a token list into chunks sized for a fixed embedding window.
+///
+/// Chunks are built up sentence-by-sentence and flushed once they reach
+/// `target` words, never exceeding `max` words. A chunk never splits a
+/// sentence mid-way unless that single sentence alone exceeds `max`, in
+/// which case it falls back to splitting at clause boundaries (and never
+/// mid-word). `overlap` controls how many trailing words of a flushed
+/// chunk's sentences are carried into the start of the next chunk.
+pub fn chunk_for_window(tokens: &[Token], target: usize, max: usize, overlap: usize) -> Vec<WindowChunk> {
     if tokens.is_empty() {
         return vec![];
     }
 
-    let indices = get_sentence_indices(tokens);
-    
-    indices
-        .into_iter()
-        .map(|(start, end)| {
-            let sentence_tokens = tokens[start..=end].to_vec();
-            let word_count = sentence_tokens
-                .iter()
-                .filter(|t| t.chunk_type == ChunkType::Text)
-                .count();
-            
-            Sentence {
-                tokens: sentence_tokens,
-                word_count,
-                start_idx: start,
-                end_idx: end,
+    let sentences = sentence_tokenize(tokens);
+    let mut chunks: Vec<WindowChunk> = Vec::new();
+    let mut current: Vec<Sentence> = Vec::new();
+    let mut current_words = 0;
+
+    for sentence in sentences {
+        if sentence.word_count > max {
+            if !current.is_empty() {
+                chunks.push(make_window_chunk(&current));
+                current = carry_overlap(&current, overlap);
+                current_words = current.iter().map(|s| s.word_count).sum();
             }
-        })
-        .collect()
+            chunks.extend(split_oversized_sentence(&sentence, target, max));
+            continue;
+        }
+
+        if current_words + sentence.word_count > max && !current.is_empty() {
+            chunks.push(make_window_chunk(&current));
+            current = carry_overlap(&current, overlap);
+            current_words = current.iter().map(|s| s.word_count).sum();
+        }
+
+        current_words += sentence.word_count;
+        current.push(sentence);
+
+        if current_words >= target {
+            chunks.push(make_window_chunk(&current));
+            current = carry_overlap(&current, overlap);
+            current_words = current.iter().map(|s| s.word_count).sum();
+        }
+    }
+
+    if !current.is_empty() {
+        chunks.push(make_window_chunk(&current));
+    }
+
+    chunks
 }
 
-/// Tokenize a list of word tokens into paragraphs
-pub fn paragraph_tokenize(tokens: &[Token]) -> Vec<Paragraph> {
-    let sentences = sentence_tokenize(tokens);
-    
-    if sentences.is_empty() {
-        return vec![];
+/// Build a [`WindowChunk`] from a run of sentences.
+fn make_window_chunk(sentences: &[Sentence]) -> WindowChunk {
+    let tokens: Vec<Token> = sentences.iter().flat_map(|s| s.tokens.clone()).collect();
+    let start_idx = sentences.first().map(|s| s.start_idx).unwrap_or(0);
+    let end_idx = sentences.last().map(|s| s.end_idx).unwrap_or(0);
+    let word_count = sentences.iter().map(|s| s.word_count).sum();
+
+    WindowChunk {
+        tokens,
+        start_idx,
+        end_idx,
+        word_count,
+    }
+}
+
+/// Carry the trailing sentences of a flushed chunk forward, up to
+/// `overlap` words, to seed the next chunk.
+fn carry_overlap(sentences: &[Sentence], overlap: usize) -> Vec<Sentence> {
+    if overlap == 0 {
+        return Vec::new();
     }
 
-    let threshold = 70;
-    let paragraph_max = 150;
+    let mut carried = Vec::new();
+    let mut words = 0;
+    for sentence in sentences.iter().rev() {
+        if words >= overlap {
+            break;
+        }
+        carried.push(sentence.clone());
+        words += sentence.word_count;
+    }
+    carried.reverse();
+    carried
+}
 
-    let mut paragraphs: Vec<Paragraph> = Vec::new();
-    let mut current_sentences: Vec<Sentence> = Vec::new();
-    let mut current_word_count = 0;
+/// Split a single oversized sentence into window chunks at clause
+/// boundaries (falling back to a hard `max` cut if no clause boundary is
+/// available), never splitting mid-word since tokens are the atomic unit.
+fn split_oversized_sentence(sentence: &Sentence, target: usize, max: usize) -> Vec<WindowChunk> {
+    let tokens = &sentence.tokens;
+    let base = sentence.start_idx;
 
-    for sentence in sentences {
-        let sentence_words = sentence.word_count;
-        
-        if current_word_count + sentence_words > paragraph_max && !current_sentences.is_empty() {
-            // Start a new paragraph
-            paragraphs.push(Paragraph {
-                sentences: std::mem::take(&mut current_sentences),
-                word_count: current_word_count,
-            });
-            current_word_count = 0;
+    let mut chunks = Vec::new();
+    let mut group: Vec<Token> = Vec::new();
+    let mut group_words = 0;
+
+    for (offset, token) in tokens.iter().enumerate() {
+        group.push(token.clone());
+        if token.chunk_type == ChunkType::Text {
+            group_words += 1;
         }
-        
-        current_word_count += sentence_words;
-        current_sentences.push(sentence);
-        
-        // If we have enough words, consider it a paragraph
-        if current_word_count >= threshold {
-            paragraphs.push(Paragraph {
-                sentences: std::mem::take(&mut current_sentences),
-                word_count: current_word_count,
+
+        let at_clause_boundary = has_last_syl(token, CLAUSE_BOUNDARIES);
+        let should_break = (group_words >= target && at_clause_boundary) || group_words >= max;
+
+        if should_break && offset + 1 < tokens.len() {
+            let end_idx = base + offset;
+            let start_idx = end_idx + 1 - group.len();
+            chunks.push(WindowChunk {
+                tokens: std::mem::take(&mut group),
+                start_idx,
+                end_idx,
+                word_count: group_words,
             });
-            current_word_count = 0;
+            group_words = 0;
         }
     }
 
-    // Don't forget the last paragraph
-    if !current_sentences.is_empty() {
-        paragraphs.push(Paragraph {
-            sentences: current_sentences,
-            word_count: current_word_count,
+    if !group.is_empty() {
+        let end_idx = base + tokens.len() - 1;
+        let start_idx = end_idx + 1 - group.len();
+        chunks.push(WindowChunk {
+            tokens: group,
+            start_idx,
+            end_idx,
+            word_count: group_words,
         });
     }
 
-    paragraphs
+    chunks
 }
 
-/// Get sentence boundary indices
-fn get_sentence_indices(tokens: &[Token]) -> Vec<(usize, usize)> {
-    if tokens.is_empty() {
-        return vec![];
+/// A boundary decision made at a single inter-token gap.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum BoundaryOutcome {
+    Boundary,
+    NoBoundary,
+}
+
+/// A partial beam-search hypothesis: the outcome chosen for every gap
+/// considered so far, together with its cumulative log-probability.
+#[derive(Debug, Clone)]
+struct Sequence {
+    outcomes: Vec<BoundaryOutcome>,
+    log_prob: f64,
+}
+
+impl PartialEq for Sequence {
+    fn eq(&self, other: &Self) -> bool {
+        self.log_prob == other.log_prob
     }
+}
 
-    // Step 1: Find unambiguous sentence boundaries (ending particle + punctuation)
-    let mut boundaries = find_boundaries(tokens, is_ending_particle_and_punct);
+impl Eq for Sequence {}
 
-    // Step 2: Find clause boundaries followed by punctuation
-    boundaries = refine_boundaries(tokens, &boundaries, is_clause_boundary_and_punct);
+impl PartialOrd for Sequence {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
 
-    // Step 3: Find verbs followed by punctuation
-    boundaries = refine_boundaries(tokens, &boundaries, is_verb_and_punct);
+impl Ord for Sequence {
+    fn cmp(&self, other: &Self) -> Ordering {
+        // BinaryHeap is a max-heap, and a higher log-prob is a better hypothesis.
+        self.log_prob.partial_cmp(&other.log_prob).unwrap_or(Ordering::Equal)
+    }
+}
 
-    // Step 4: Find verbs followed by clause boundaries (for long sentences)
-    boundaries = refine_long_sentences(tokens, &boundaries, is_verb_and_clause_boundary, 30);
+/// Feature weights for scoring a BOUNDARY decision at a token gap.
+///
+/// These mirror the predicates used by the greedy heuristic in
+/// [`SentenceTokenizer::get_sentence_indices`], but combine them into a single weighted score
+/// that is turned into a probability via softmax instead of being applied
+/// as an irreversible rule.
+#[derive(Debug, Clone)]
+struct BoundaryWeights {
+    ending_particle: f64,
+    verb: f64,
+    clause_boundary: f64,
+    length: f64,
+    bias: f64,
+}
 
-    // Step 5: Join short sentences without verbs
-    boundaries = join_no_verb_sentences(tokens, &boundaries, 4);
+impl Default for BoundaryWeights {
+    fn default() -> Self {
+        BoundaryWeights {
+            ending_particle: 4.0,
+            verb: 2.5,
+            clause_boundary: 1.0,
+            length: 0.03,
+            bias: -1.0,
+        }
+    }
+}
 
-    boundaries
+/// Statistical, beam-search based alternative to the greedy rule cascade in
+/// [`sentence_tokenize`].
+///
+/// Instead of committing to each boundary decision as soon as a rule fires,
+/// the segmenter scores every inter-token gap with a small weighted feature
+/// function, then searches for the globally highest-probability sequence of
+/// BOUNDARY/NO_BOUNDARY outcomes with a beam of bounded width. This lets an
+/// early, locally-plausible-but-wrong decision (e.g. a clause-boundary
+/// particle that wasn't actually a sentence end) be discarded in favor of a
+/// better-scoring path, which the purely greedy heuristic can never recover
+/// from.
+#[derive(Debug, Clone)]
+pub struct SentenceSegmenter {
+    beam_width: usize,
+    weights: BoundaryWeights,
+}
+
+impl SentenceSegmenter {
+    /// Create a segmenter with the default beam width (8).
+    pub fn new() -> Self {
+        Self::with_beam_width(8)
+    }
+
+    /// Create a segmenter with a custom beam width.
+    ///
+    /// A beam width of 1 degenerates to greedy best-first search; wider
+    /// beams explore more candidate sequences at the cost of more work.
+    pub fn with_beam_width(beam_width: usize) -> Self {
+        SentenceSegmenter {
+            beam_width: beam_width.max(1),
+            weights: BoundaryWeights::default(),
+        }
+    }
+
+    /// Score every inter-token gap and return the highest-probability
+    /// `(start, end)` sentence spans (inclusive end indices), as consumed
+    /// by [`sentence_tokenize`].
+    pub fn segment(&self, tokens: &[Token]) -> Vec<(usize, usize)> {
+        if tokens.is_empty() {
+            return vec![];
+        }
+
+        let gaps = tokens.len() - 1;
+        if gaps == 0 {
+            return vec![(0, 0)];
+        }
+
+        let mut beam: BinaryHeap<Sequence> = BinaryHeap::new();
+        beam.push(Sequence {
+            outcomes: Vec::with_capacity(gaps),
+            log_prob: 0.0,
+        });
+
+        for i in 0..gaps {
+            let mut candidates: BinaryHeap<Sequence> = BinaryHeap::new();
+
+            for seq in beam.drain() {
+                let running_len = seq
+                    .outcomes
+                    .iter()
+                    .rev()
+                    .take_while(|o| **o != BoundaryOutcome::Boundary)
+                    .count()
+                    + 1;
+                let (p_boundary, p_no_boundary) = self.outcome_probs(tokens, i, running_len);
+
+                let mut with_boundary = seq.clone();
+                with_boundary.outcomes.push(BoundaryOutcome::Boundary);
+                with_boundary.log_prob += p_boundary.ln();
+                candidates.push(with_boundary);
+
+                let mut without_boundary = seq;
+                without_boundary.outcomes.push(BoundaryOutcome::NoBoundary);
+                without_boundary.log_prob += p_no_boundary.ln();
+                candidates.push(without_boundary);
+            }
+
+            // Prune back to the beam width, keeping the highest log-probs.
+            let mut pruned = candidates.into_sorted_vec();
+            let drop = pruned.len().saturating_sub(self.beam_width);
+            pruned.drain(..drop);
+            beam = pruned.into_iter().collect();
+        }
+
+        let best = beam
+            .into_sorted_vec()
+            .pop()
+            .expect("beam is never empty after expansion");
+
+        outcomes_to_spans(&best.outcomes)
+    }
+
+    /// Probability of {BOUNDARY, NO_BOUNDARY} at the gap between
+    /// `tokens[i]` and `tokens[i + 1]`, given the current sentence's
+    /// running length in tokens.
+    fn outcome_probs(&self, tokens: &[Token], i: usize, running_len: usize) -> (f64, f64) {
+        let t1 = &tokens[i];
+        let t2 = &tokens[i + 1];
+
+        let mut logit = self.weights.bias;
+        if is_ending_particle_and_punct(t1, t2) {
+            logit += self.weights.ending_particle;
+        }
+        if is_verb_and_punct(t1, t2) {
+            logit += self.weights.verb;
+        }
+        if is_clause_boundary_and_punct(t1, t2) {
+            logit += self.weights.clause_boundary;
+        }
+        logit += self.weights.length * running_len as f64;
+
+        softmax2(logit, 0.0)
+    }
+
+    /// Tokenize a list of word tokens into sentences using beam search.
+    ///
+    /// This is a drop-in alternative to [`sentence_tokenize`] for text with
+    /// ambiguous punctuation, falling back to the same [`Sentence`]
+    /// representation.
+    pub fn sentence_tokenize(&self, tokens: &[Token]) -> Vec<Sentence> {
+        if tokens.is_empty() {
+            return vec![];
+        }
+
+        self.segment(tokens)
+            .into_iter()
+            .map(|(start, end)| {
+                let sentence_tokens = tokens[start..=end].to_vec();
+                let word_count = sentence_tokens
+                    .iter()
+                    .filter(|t| t.chunk_type == ChunkType::Text)
+                    .count();
+
+                Sentence {
+                    tokens: sentence_tokens,
+                    word_count,
+                    start_idx: start,
+                    end_idx: end,
+                }
+            })
+            .collect()
+    }
+}
+
+impl Default for SentenceSegmenter {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Softmax over two logits, returning `(p_a, p_b)`.
+fn softmax2(a: f64, b: f64) -> (f64, f64) {
+    let m = a.max(b);
+    let ea = (a - m).exp();
+    let eb = (b - m).exp();
+    let sum = ea + eb;
+    (ea / sum, eb / sum)
+}
+
+/// Convert a sequence of per-gap BOUNDARY/NO_BOUNDARY outcomes into
+/// inclusive `(start, end)` token spans.
+fn outcomes_to_spans(outcomes: &[BoundaryOutcome]) -> Vec<(usize, usize)> {
+    let mut spans = Vec::new();
+    let mut start = 0;
+
+    for (i, outcome) in outcomes.iter().enumerate() {
+        if *outcome == BoundaryOutcome::Boundary {
+            spans.push((start, i));
+            start = i + 1;
+        }
+    }
+
+    spans.push((start, outcomes.len()));
+    spans
 }
 
 /// Find initial sentence boundaries based on a test function
@@ -259,49 +803,6 @@ where
     result
 }
 
-/// Join short sentences without verbs to adjacent sentences
-fn join_no_verb_sentences(
-    tokens: &[Token],
-    boundaries: &[(usize, usize)],
-    threshold: usize,
-) -> Vec<(usize, usize)> {
-    let mut result: Vec<(usize, usize)> = boundaries.to_vec();
-    let mut i = 0;
-
-    while i < result.len() {
-        let (start, end) = result[i];
-        let length = end - start + 1;
-
-        if length <= threshold {
-            // Check if this segment has a verb
-            let has_verb = tokens[start..=end]
-                .iter()
-                .any(|t| t.pos.as_deref() == Some("VERB") && !has_last_syl(t, DAGDRA));
-
-            if !has_verb {
-                // Try to join with adjacent segment
-                if i + 1 < result.len() && has_last_syl(&tokens[end], CLAUSE_BOUNDARIES) {
-                    // Join with next
-                    result[i + 1].0 = start;
-                    result.remove(i);
-                    continue;
-                } else if i > 0 {
-                    // Join with previous
-                    let prev_end = result[i - 1].1;
-                    if !has_last_syl(&tokens[prev_end], ENDING_PARTICLES) {
-                        result[i - 1].1 = end;
-                        result.remove(i);
-                        continue;
-                    }
-                }
-            }
-        }
-        i += 1;
-    }
-
-    result
-}
-
 // Helper functions for sentence boundary detection
 
 #[allow(dead_code)]
@@ -309,13 +810,20 @@ fn is_word(token: &Token) -> bool {
     token.chunk_type == ChunkType::Text
 }
 
-fn has_last_syl(token: &Token, patterns: &[&str]) -> bool {
+fn has_last_syl<S: AsRef<str>>(token: &Token, patterns: &[S]) -> bool {
     if token.syls.is_empty() {
         return false;
     }
-    
+
     let last_syl = format!("{}་", token.syls.last().unwrap());
-    patterns.iter().any(|p| last_syl == *p)
+    patterns.iter().any(|p| last_syl == p.as_ref())
+}
+
+/// Whether a token's chunk type is one that can end a sentence: an ordinary
+/// `Punct` shad, or a recognized closing mark (e.g. the double shad `༎` or
+/// the rin-chen-spungs-shad), which carry the same sentence-terminating role.
+fn is_sentence_final_punct(token: &Token) -> bool {
+    matches!(token.chunk_type, ChunkType::Punct | ChunkType::ClosingMark)
 }
 
 fn is_ending_particle(token: &Token) -> bool {
@@ -323,18 +831,18 @@ fn is_ending_particle(token: &Token) -> bool {
 }
 
 fn is_ending_particle_and_punct(token1: &Token, token2: &Token) -> bool {
-    is_ending_particle(token1) && token2.chunk_type == ChunkType::Punct
+    is_ending_particle(token1) && is_sentence_final_punct(token2)
 }
 
 fn is_clause_boundary_and_punct(token1: &Token, token2: &Token) -> bool {
     (has_last_syl(token1, CLAUSE_BOUNDARIES) || has_last_syl(token1, ENDING_WORDS))
-        && token2.chunk_type == ChunkType::Punct
+        && is_sentence_final_punct(token2)
 }
 
 fn is_verb_and_punct(token1: &Token, token2: &Token) -> bool {
     let is_verb = (token1.pos.as_deref() == Some("VERB") && !has_last_syl(token1, DAGDRA))
         || has_last_syl(token1, ENDING_VERBS);
-    is_verb && token2.chunk_type == ChunkType::Punct
+    is_verb && is_sentence_final_punct(token2)
 }
 
 fn is_verb_and_clause_boundary(token1: &Token, token2: &Token) -> bool {
@@ -385,6 +893,136 @@ mod tests {
         assert!(!paragraphs[0].sentences.is_empty());
     }
 
+    #[test]
+    fn test_sentence_tokenizer_default_matches_free_function() {
+        let tokens = vec![
+            make_token("བཀྲ་ཤིས་", ChunkType::Text, Some("NOUN")),
+            make_token("།", ChunkType::Punct, None),
+        ];
+
+        let via_struct = SentenceTokenizer::new().sentence_tokenize(&tokens);
+        let via_free_fn = sentence_tokenize(&tokens);
+
+        assert_eq!(via_struct.len(), via_free_fn.len());
+    }
+
+    #[test]
+    fn test_sentence_tokenizer_custom_join_threshold() {
+        // "ཅིག་" followed by a shad ends a sentence, giving two short
+        // verb-less sentences that the default threshold would join.
+        let tokens = vec![
+            make_token("ཅིག་", ChunkType::Text, Some("PART")),
+            make_token("།", ChunkType::Punct, None),
+            make_token("བདེ་ལེགས་", ChunkType::Text, Some("NOUN")),
+            make_token("།", ChunkType::Punct, None),
+        ];
+
+        // With join_threshold 0, no verb-less sentence joining happens.
+        let tokenizer = SentenceTokenizer::new().with_join_threshold(0);
+        let sentences = tokenizer.sentence_tokenize(&tokens);
+
+        assert_eq!(sentences.len(), 2);
+    }
+
+    #[test]
+    fn test_sentence_tokenizer_custom_paragraph_limits() {
+        let tokens = vec![
+            make_token("བཀྲ་ཤིས་", ChunkType::Text, Some("NOUN")),
+            make_token("།", ChunkType::Punct, None),
+        ];
+
+        let tokenizer = SentenceTokenizer::new().with_paragraph_limits(1, 1);
+        let paragraphs = tokenizer.paragraph_tokenize(&tokens);
+
+        assert_eq!(paragraphs.len(), 1);
+    }
+
+    #[test]
+    fn test_chunk_for_window_respects_max() {
+        let tokens = vec![
+            make_token("བཀྲ་ཤིས་", ChunkType::Text, Some("NOUN")),
+            make_token("།", ChunkType::Punct, None),
+            make_token("བདེ་ལེགས་", ChunkType::Text, Some("NOUN")),
+            make_token("།", ChunkType::Punct, None),
+            make_token("ཡིན་", ChunkType::Text, Some("VERB")),
+            make_token("སོ་", ChunkType::Text, Some("PART")),
+            make_token("།", ChunkType::Punct, None),
+        ];
+
+        let chunks = chunk_for_window(&tokens, 1, 2, 0);
+
+        assert!(!chunks.is_empty());
+        for chunk in &chunks {
+            assert!(chunk.word_count <= 2);
+        }
+
+        // Token indices map back to the source and stay contiguous.
+        assert_eq!(chunks[0].start_idx, 0);
+        assert_eq!(chunks.last().unwrap().end_idx, tokens.len() - 1);
+    }
+
+    #[test]
+    fn test_chunk_for_window_overlap_carries_forward() {
+        let tokens = vec![
+            make_token("བཀྲ་ཤིས་", ChunkType::Text, Some("NOUN")),
+            make_token("།", ChunkType::Punct, None),
+            make_token("བདེ་ལེགས་", ChunkType::Text, Some("NOUN")),
+            make_token("།", ChunkType::Punct, None),
+        ];
+
+        let chunks = chunk_for_window(&tokens, 1, 1, 1);
+        assert!(chunks.len() >= 2);
+    }
+
+    #[test]
+    fn test_chunk_for_window_empty() {
+        assert!(chunk_for_window(&[], 10, 20, 0).is_empty());
+    }
+
+    #[test]
+    fn test_segmenter_basic() {
+        let tokens = vec![
+            make_token("བཀྲ་ཤིས་", ChunkType::Text, Some("NOUN")),
+            make_token("བདེ་ལེགས་", ChunkType::Text, Some("NOUN")),
+            make_token("།", ChunkType::Punct, None),
+            make_token("ཡིན་", ChunkType::Text, Some("VERB")),
+            make_token("སོ་", ChunkType::Text, Some("PART")),
+            make_token("།", ChunkType::Punct, None),
+        ];
+
+        let segmenter = SentenceSegmenter::new();
+        let spans = segmenter.segment(&tokens);
+
+        // Spans must be contiguous and cover every token exactly once.
+        assert_eq!(spans[0].0, 0);
+        assert_eq!(spans.last().unwrap().1, tokens.len() - 1);
+        for pair in spans.windows(2) {
+            assert_eq!(pair[1].0, pair[0].1 + 1);
+        }
+    }
+
+    #[test]
+    fn test_segmenter_sentence_tokenize() {
+        let tokens = vec![
+            make_token("བཀྲ་ཤིས་", ChunkType::Text, Some("NOUN")),
+            make_token("།", ChunkType::Punct, None),
+        ];
+
+        let segmenter = SentenceSegmenter::with_beam_width(4);
+        let sentences = segmenter.sentence_tokenize(&tokens);
+
+        assert!(!sentences.is_empty());
+        let total_tokens: usize = sentences.iter().map(|s| s.tokens.len()).sum();
+        assert_eq!(total_tokens, tokens.len());
+    }
+
+    #[test]
+    fn test_segmenter_empty() {
+        let segmenter = SentenceSegmenter::new();
+        assert!(segmenter.segment(&[]).is_empty());
+        assert!(segmenter.sentence_tokenize(&[]).is_empty());
+    }
+
     #[test]
     fn test_has_last_syl() {
         let token = make_token("ཡིན་སོ་", ChunkType::Text, Some("PART"));
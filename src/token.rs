@@ -21,6 +21,13 @@ pub enum ChunkType {
     Latin,
     /// CJK text
     Cjk,
+    /// Whitespace (only emitted by lossless chunking)
+    Space,
+    /// A yig-mgo / head mark opening a section (e.g. `༄`, `༄༅`, `༄༅༅`)
+    HeadMark,
+    /// A recognized sentence/section-closing mark (e.g. the double shad `༎`
+    /// or the rin-chen-spungs-shad), distinct from an ordinary shad
+    ClosingMark,
     /// Other/unknown
     Other,
 }
@@ -35,6 +42,9 @@ impl ChunkType {
             ChunkType::Sym => "SYM",
             ChunkType::Latin => "LATIN",
             ChunkType::Cjk => "CJK",
+            ChunkType::Space => "SPACE",
+            ChunkType::HeadMark => "HEAD_MARK",
+            ChunkType::ClosingMark => "CLOSING_MARK",
             ChunkType::Other => "OTHER",
         }
     }
@@ -84,6 +94,27 @@ pub struct Token {
 
     /// Whether this token has had a dagdra merged into it
     pub has_merged_dagdra: bool,
+
+    /// Ranked spelling-correction candidates, populated for unknown
+    /// (`NO_POS`) tokens when tokenizing with suggestions enabled, or by
+    /// [`crate::modifiers::normalize_spelling`]'s dictionary-backed pass
+    pub suggestions: Vec<Suggestion>,
+
+    /// Confidence in `suggestions`' top candidate as a replacement for this
+    /// token's lemma, set by [`crate::modifiers::normalize_spelling`]
+    pub spelling_confidence: Option<f64>,
+}
+
+/// A ranked spelling-correction candidate for an unknown word, found via a
+/// bounded edit-distance search over the dictionary trie.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct Suggestion {
+    /// Syllables of the candidate word
+    pub syls: Vec<String>,
+    /// Edit distance (at syllable granularity) from the input syllables
+    pub distance: usize,
+    /// Frequency of the candidate word, if known
+    pub freq: Option<u32>,
 }
 
 /// Information about affixation in a token
@@ -96,7 +127,7 @@ pub struct AffixationInfo {
 }
 
 /// A word sense/meaning from the dictionary
-#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub struct Sense {
     /// Part-of-speech for this sense
     pub pos: Option<String>,
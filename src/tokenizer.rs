@@ -6,11 +6,23 @@
 use std::sync::Arc;
 use unicode_normalization::UnicodeNormalization;
 
-use crate::chunker::{Chunk, Chunker};
+use crate::chunker::{Chunk, Chunker, TokenError};
 use crate::modifiers::apply_all_modifiers;
-use crate::token::{ChunkType, Token};
+use crate::token::{ChunkType, Suggestion, Token};
 use crate::trie::{Trie, TrieNode};
 
+/// Normalize `text` to NFC (canonical composition).
+///
+/// Tibetan text in the wild mixes precomposed and decomposed forms (e.g.
+/// stacked vowels), which silently breaks dictionary lookup if the trie's
+/// keys and the query aren't in the same canonical form. [`Tokenizer`]
+/// applies this normalization internally before chunking; this helper is
+/// exposed for callers that need the same canonicalization before diffing
+/// or indexing text themselves.
+pub fn normalize_tibetan(text: &str) -> String {
+    text.nfc().collect()
+}
+
 /// The main tokenizer
 pub struct Tokenizer {
     /// The dictionary trie (shared reference)
@@ -45,34 +57,121 @@ impl Tokenizer {
 
     /// Tokenize a string with configurable options
     pub fn tokenize_with_options(&self, text: &str, split_affixes: bool) -> Vec<Token> {
-        self.tokenize_with_full_options(text, split_affixes, false)
+        self.tokenize_with_full_options(text, split_affixes, false, false, true)
     }
 
     /// Tokenize a string with all options
-    /// 
+    ///
     /// # Arguments
     /// * `text` - The text to tokenize
     /// * `split_affixes` - Whether to split affixed particles into separate tokens
     /// * `spaces_as_punct` - Whether to treat spaces as punctuation tokens
-    pub fn tokenize_with_full_options(&self, text: &str, split_affixes: bool, spaces_as_punct: bool) -> Vec<Token> {
-        // Normalize Unicode (NFC normalization)
-        let normalized: String = text.nfc().collect();
-        
+    /// * `preserve_source_offsets` - Whether `start`/`len` on the returned tokens
+    ///   should be translated back to byte ranges in `text` as given (before NFC
+    ///   normalization), instead of ranges over the normalized string
+    /// * `normalize` - Whether to NFC-normalize `text` before chunking (see
+    ///   [`normalize_tibetan`]). Disable only if `text` is already known to be
+    ///   normalized, or to diagnose normalization-dependent lookup misses.
+    pub fn tokenize_with_full_options(
+        &self,
+        text: &str,
+        split_affixes: bool,
+        spaces_as_punct: bool,
+        preserve_source_offsets: bool,
+        normalize: bool,
+    ) -> Vec<Token> {
+        let (normalized, offset_map) = if !normalize {
+            (text.to_string(), None)
+        } else if preserve_source_offsets {
+            let (normalized, offset_map) = normalize_with_offsets(text);
+            (normalized, Some(offset_map))
+        } else {
+            (text.nfc().collect(), None)
+        };
+
         let chunker = Chunker::new(&normalized);
         let chunks = chunker.make_chunks();
         let mut tokens = self.tokenize_chunks(&chunks, &normalized);
-        
+
         // If spaces_as_punct is enabled, split space-containing tokens
         if spaces_as_punct {
             tokens = self.split_spaces_as_punct(tokens);
         }
-        
+
         // Apply post-processing
         apply_all_modifiers(&mut tokens, split_affixes);
-        
+
+        if let Some(offset_map) = offset_map {
+            remap_token_offsets(&mut tokens, &offset_map);
+        }
+
         tokens
     }
 
+    /// Tokenize `text`, appending the resulting tokens into `buf`. Clears
+    /// `buf` first.
+    ///
+    /// The tokens are still built by [`Tokenizer::tokenize`] in a fresh
+    /// `Vec` and then moved into `buf`, so each call allocates; only `buf`'s
+    /// own capacity carries over between calls.
+    pub fn tokenize_into(&self, text: &str, buf: &mut Vec<Token>) {
+        buf.clear();
+        buf.extend(self.tokenize(text));
+    }
+
+    /// Incrementally re-tokenize `original_text` after a localized edit,
+    /// instead of re-running [`Tokenizer::tokenize`] over the whole document.
+    ///
+    /// `prev_tokens` must be the tokens previously returned for
+    /// `original_text`. `edit_range` is the byte range of `original_text`
+    /// being replaced, and `new_substring` is its replacement (an empty
+    /// range is a pure insertion; an empty `new_substring` is a deletion).
+    ///
+    /// Only the maximal run of non-punctuation tokens containing the edit
+    /// is re-tokenized (mirroring how [`Tokenizer::tokenize_chunks`] already
+    /// treats punctuation as a hard run boundary); every other token is kept
+    /// as-is, with tokens after the edit shifted by the length delta. If the
+    /// edit itself overlaps a punctuation token - which may be changing a
+    /// run boundary - this falls back to tokenizing the whole new text.
+    pub fn reparse(
+        &self,
+        prev_tokens: &[Token],
+        original_text: &str,
+        edit_range: std::ops::Range<usize>,
+        new_substring: &str,
+    ) -> Vec<Token> {
+        let mut new_text =
+            String::with_capacity(original_text.len() - edit_range.len() + new_substring.len());
+        new_text.push_str(&original_text[..edit_range.start]);
+        new_text.push_str(new_substring);
+        new_text.push_str(&original_text[edit_range.end..]);
+
+        let Some((start_idx, end_idx)) = affected_window(prev_tokens, &edit_range) else {
+            return self.tokenize(&new_text);
+        };
+
+        let delta = new_substring.len() as isize - edit_range.len() as isize;
+        let window_start = prev_tokens[start_idx].start;
+        let window_end_old = prev_tokens[end_idx - 1].start + prev_tokens[end_idx - 1].len;
+        let window_end_new = (window_end_old as isize + delta) as usize;
+
+        let mut retokenized = self.tokenize(&new_text[window_start..window_end_new]);
+        for token in &mut retokenized {
+            token.start += window_start;
+        }
+
+        let mut result = Vec::with_capacity(prev_tokens.len() - (end_idx - start_idx) + retokenized.len());
+        result.extend_from_slice(&prev_tokens[..start_idx]);
+        result.extend(retokenized);
+        result.extend(prev_tokens[end_idx..].iter().map(|token| {
+            let mut token = token.clone();
+            token.start = (token.start as isize + delta) as usize;
+            token
+        }));
+
+        result
+    }
+
     /// Split tokens that contain spaces into separate space tokens
     fn split_spaces_as_punct(&self, tokens: Vec<Token>) -> Vec<Token> {
         let mut result = Vec::new();
@@ -167,6 +266,219 @@ impl Tokenizer {
         result
     }
 
+    /// Suggest ranked spelling corrections for a single unmatched token, via
+    /// a bounded Damerau-Levenshtein search over the trie. Use
+    /// [`Tokenizer::tokenize_with_suggestions`] to populate every unknown
+    /// token produced by a tokenization pass in one go.
+    pub fn suggest(&self, token: &Token, max_dist: usize) -> Vec<Suggestion> {
+        self.trie.fuzzy_lookup(&token.syls, max_dist)
+    }
+
+    /// Tokenize a string, populating [`Token::suggestions`] with ranked
+    /// spelling corrections for any unknown (`NO_POS`) token, found via a
+    /// bounded edit-distance search over the trie.
+    pub fn tokenize_with_suggestions(&self, text: &str, max_dist: usize) -> Vec<Token> {
+        let mut tokens = self.tokenize(text);
+
+        for token in &mut tokens {
+            if token.pos.as_deref() == Some("NO_POS") && !token.syls.is_empty() {
+                token.suggestions = self.suggest(token, max_dist);
+            }
+        }
+
+        tokens
+    }
+
+    /// Tokenize a string using frequency-weighted maximum-probability
+    /// segmentation instead of greedy longest-match.
+    ///
+    /// Unlike [`Tokenizer::tokenize`], which always keeps the longest
+    /// dictionary match at each position, this builds a DAG of every
+    /// candidate word over each run of syllable chunks and picks the path
+    /// through it with the highest total log-probability, which better
+    /// disambiguates overlapping compounds.
+    pub fn tokenize_max_prob(&self, text: &str) -> Vec<Token> {
+        self.tokenize_max_prob_with_options(text, true)
+    }
+
+    /// Maximum-probability tokenization with configurable affix splitting.
+    pub fn tokenize_max_prob_with_options(&self, text: &str, split_affixes: bool) -> Vec<Token> {
+        let normalized: String = text.nfc().collect();
+
+        let chunker = Chunker::new(&normalized);
+        let chunks = chunker.make_chunks();
+        let mut tokens = self.tokenize_chunks_max_prob(&chunks, &normalized);
+
+        apply_all_modifiers(&mut tokens, split_affixes);
+
+        tokens
+    }
+
+    /// Tokenize pre-chunked text using maximum-probability segmentation.
+    pub fn tokenize_chunks_max_prob(&self, chunks: &[Chunk<'_>], original_text: &str) -> Vec<Token> {
+        let mut tokens: Vec<Token> = Vec::new();
+        let mut i = 0;
+
+        while i < chunks.len() {
+            // Non-syllable chunks are passed through as-is
+            if chunks[i].syl.is_none() {
+                let chunk = &chunks[i];
+                tokens.push(Token::with_text(
+                    original_text[chunk.start..chunk.start + chunk.len].to_string(),
+                    chunk.start,
+                    chunk.len,
+                    chunk.chunk_type,
+                ));
+                i += 1;
+                continue;
+            }
+
+            // Find the maximal run of syllable chunks starting here
+            let run_start = i;
+            let mut run_end = i;
+            while run_end < chunks.len() && chunks[run_end].syl.is_some() {
+                run_end += 1;
+            }
+
+            tokens.extend(self.segment_run_max_prob(&chunks[run_start..run_end], original_text));
+            i = run_end;
+        }
+
+        tokens
+    }
+
+    /// Collect every valid dictionary match starting at `start` within a
+    /// run of syllable chunks, as `(end_idx, node)` pairs ordered by
+    /// increasing `end_idx`.
+    fn collect_matches<'a>(&'a self, run: &[Chunk<'_>], start: usize) -> Vec<(usize, &'a TrieNode)> {
+        let mut matches = Vec::new();
+        let mut current_node: Option<&TrieNode> = None;
+        let mut walker = start;
+
+        while walker < run.len() {
+            let Some(ref syl) = run[walker].syl else { break };
+
+            match self.trie.walk(syl, current_node) {
+                Some(next_node) => {
+                    current_node = Some(next_node);
+                    if next_node.is_match() {
+                        matches.push((walker, next_node));
+                    }
+                    walker += 1;
+                }
+                None => break,
+            }
+        }
+
+        matches
+    }
+
+    /// Segment a single maximal run of syllable chunks by finding the
+    /// highest-log-probability path through the DAG of candidate
+    /// dictionary matches (right-to-left DP).
+    fn segment_run_max_prob(&self, run: &[Chunk<'_>], original_text: &str) -> Vec<Token> {
+        let n = run.len();
+        if n == 0 {
+            return vec![];
+        }
+
+        // Floor log-probability assigned to a single unmatched syllable,
+        // so out-of-vocabulary runs still produce a (low-probability) path.
+        const UNMATCHED_LOG_PROB: f64 = -10.0;
+
+        let log_total = (self.trie.total_freq() as f64 + 1.0).ln();
+        let edges: Vec<Vec<(usize, &TrieNode)>> = (0..n).map(|i| self.collect_matches(run, i)).collect();
+
+        // route[idx] = best total log-probability of segmenting run[idx..n]
+        let mut route = vec![0.0_f64; n + 1];
+        let mut best_end = vec![0usize; n];
+        let mut best_node: Vec<Option<&TrieNode>> = vec![None; n];
+
+        for idx in (0..n).rev() {
+            let mut best_score = UNMATCHED_LOG_PROB + route[idx + 1];
+            let mut chosen_end = idx;
+            let mut chosen_node: Option<&TrieNode> = None;
+
+            for &(end, node) in &edges[idx] {
+                let freq = node.data.as_ref().and_then(|d| d.freq).unwrap_or(0) as f64;
+                let word_logprob = (freq + 1.0).ln() - log_total;
+                let score = word_logprob + route[end + 1];
+
+                // Prefer the longer/later match on ties, matching the
+                // longest-match intuition of the greedy tokenizer.
+                if score >= best_score {
+                    best_score = score;
+                    chosen_end = end;
+                    chosen_node = Some(node);
+                }
+            }
+
+            route[idx] = best_score;
+            best_end[idx] = chosen_end;
+            best_node[idx] = chosen_node;
+        }
+
+        // Backtrack from the start, emitting one token per DP step
+        let mut tokens = Vec::new();
+        let mut idx = 0;
+
+        while idx < n {
+            let end = best_end[idx];
+            let start = run[idx].start;
+            let end_chunk = &run[end];
+            let stop = end_chunk.start + end_chunk.len;
+
+            let mut token = Token::with_text(original_text[start..stop].to_string(), start, stop - start, ChunkType::Text);
+            token.syls = run[idx..=end]
+                .iter()
+                .filter_map(|c| c.syl.as_ref().map(|s| s.to_string()))
+                .collect();
+
+            if let Some(node) = best_node[idx] {
+                if let Some(ref data) = node.data {
+                    token.pos = data.pos.clone();
+                    token.lemma = data.lemma.clone();
+                    token.freq = data.freq;
+                    token.is_skrt = data.skrt;
+                    token.senses = data.senses.clone();
+
+                    if let Some(ref affix_info) = data.affixation {
+                        token.affixation = Some(crate::token::AffixationInfo {
+                            len: affix_info.len,
+                            aa: affix_info.aa,
+                        });
+                    }
+                }
+            } else {
+                token.pos = Some("NO_POS".to_string());
+            }
+
+            tokens.push(token);
+            idx = end + 1;
+        }
+
+        tokens
+    }
+
+    /// Tokenize a string, additionally validating Tibetan syllable structure
+    /// and returning every problem found alongside the best-effort tokens.
+    ///
+    /// See [`Chunker::make_chunks_validated`]: tokenization itself never
+    /// fails, so callers that don't care about structural validity should
+    /// keep using [`Tokenizer::tokenize`] - this is for IME/proofreading
+    /// callers that want to highlight the broken spans.
+    pub fn tokenize_with_errors(&self, text: &str, split_affixes: bool) -> (Vec<Token>, Vec<TokenError>) {
+        let normalized: String = text.nfc().collect();
+
+        let chunker = Chunker::new(&normalized);
+        let (chunks, errors) = chunker.make_chunks_validated();
+        let mut tokens = self.tokenize_chunks(&chunks, &normalized);
+
+        apply_all_modifiers(&mut tokens, split_affixes);
+
+        (tokens, errors)
+    }
+
     /// Tokenize without post-processing (raw tokenization)
     pub fn tokenize_raw(&self, text: &str) -> Vec<Token> {
         // Normalize Unicode (NFC normalization)
@@ -178,7 +490,7 @@ impl Tokenizer {
     }
 
     /// Tokenize pre-chunked text
-    pub fn tokenize_chunks(&self, chunks: &[Chunk], original_text: &str) -> Vec<Token> {
+    pub fn tokenize_chunks(&self, chunks: &[Chunk<'_>], original_text: &str) -> Vec<Token> {
         let mut tokens: Vec<Token> = Vec::new();
         let mut i = 0;
 
@@ -207,7 +519,7 @@ impl Tokenizer {
     }
 
     /// Find the longest matching word starting at position i
-    fn longest_match(&self, chunks: &[Chunk], original_text: &str, start_i: usize) -> (Token, usize) {
+    fn longest_match(&self, chunks: &[Chunk<'_>], original_text: &str, start_i: usize) -> (Token, usize) {
         let mut walker = start_i;
         let mut current_node: Option<&TrieNode> = None;
         let mut last_match_idx: Option<usize> = None;
@@ -222,7 +534,7 @@ impl Tokenizer {
             if let Some(ref syl) = chunk.syl {
                 if let Some(next_node) = self.trie.walk(syl, current_node) {
                     current_node = Some(next_node);
-                    syls.push(syl.clone());
+                    syls.push(syl.to_string());
 
                     // Record if this is a valid word ending
                     if next_node.is_match() {
@@ -290,7 +602,7 @@ impl Tokenizer {
             );
 
             if let Some(ref syl) = chunk.syl {
-                token.syls = vec![syl.clone()];
+                token.syls = vec![syl.to_string()];
             }
 
             // Mark as unknown (no POS)
@@ -301,6 +613,126 @@ impl Tokenizer {
     }
 }
 
+/// NFC-normalize `text`, returning the normalized string along with, for
+/// each output char, the byte span in `text` (pre-normalization) that
+/// produced it.
+///
+/// Normalization runs per grapheme cluster (a base character followed by
+/// any trailing combining marks) so that a cluster's composed/decomposed
+/// output chars all map back to that cluster's full source span. This
+/// covers NFC's offset-shifting behavior (e.g. base + combining vowel
+/// sign composing into one precomposed char) without needing a general
+/// multi-cluster alignment algorithm.
+fn normalize_with_offsets(text: &str) -> (String, SourceOffsetMap) {
+    let chars: Vec<(usize, char)> = text.char_indices().collect();
+    let mut normalized = String::new();
+    let mut source_spans: Vec<(usize, usize)> = Vec::new();
+    let mut char_byte_starts: Vec<usize> = Vec::new();
+
+    let mut i = 0;
+    while i < chars.len() {
+        let cluster_start = chars[i].0;
+        let mut j = i + 1;
+        while j < chars.len() && is_combining_mark(chars[j].1) {
+            j += 1;
+        }
+        let cluster_end = if j < chars.len() { chars[j].0 } else { text.len() };
+
+        let cluster = &text[cluster_start..cluster_end];
+        for c in cluster.nfc() {
+            char_byte_starts.push(normalized.len());
+            normalized.push(c);
+            source_spans.push((cluster_start, cluster_end));
+        }
+
+        i = j;
+    }
+
+    (normalized, SourceOffsetMap { char_byte_starts, source_spans })
+}
+
+/// Whether `c` is a combining mark (non-zero canonical combining class),
+/// i.e. attaches to the preceding base character rather than starting a
+/// new grapheme cluster.
+fn is_combining_mark(c: char) -> bool {
+    unicode_normalization::char::canonical_combining_class(c) != 0
+}
+
+/// Per-char alignment between a normalized string and its pre-normalization
+/// source, built by [`normalize_with_offsets`].
+struct SourceOffsetMap {
+    /// Byte offset of each normalized char, within the normalized string
+    char_byte_starts: Vec<usize>,
+    /// Source byte span that produced each normalized char
+    source_spans: Vec<(usize, usize)>,
+}
+
+impl SourceOffsetMap {
+    /// Index of the char starting exactly at normalized byte offset `byte_pos`.
+    fn char_index_at(&self, byte_pos: usize) -> Option<usize> {
+        self.char_byte_starts.binary_search(&byte_pos).ok()
+    }
+}
+
+/// Translate each token's `start`/`len` (byte offsets into the normalized
+/// string) back to the corresponding byte range in the original source
+/// text, using the alignment built by [`normalize_with_offsets`].
+fn remap_token_offsets(tokens: &mut [Token], offset_map: &SourceOffsetMap) {
+    for token in tokens.iter_mut() {
+        let end_byte = token.start + token.len;
+
+        let Some(start_idx) = offset_map.char_index_at(token.start) else { continue };
+        let end_idx = offset_map.char_index_at(end_byte).unwrap_or(offset_map.source_spans.len());
+
+        let source_start = offset_map.source_spans[start_idx].0;
+        let source_end = if end_idx == 0 {
+            source_start
+        } else {
+            offset_map.source_spans[end_idx - 1].1
+        };
+
+        token.start = source_start;
+        token.len = source_end.saturating_sub(source_start);
+    }
+}
+
+/// Find the `[start_idx, end_idx)` range of `prev_tokens` spanning the
+/// maximal run of non-punctuation tokens that contains `edit_range`,
+/// expanding outward to the nearest [`ChunkType::Punct`] token on either
+/// side (exclusive) so the re-tokenized window keeps enough syllable/word
+/// context. Returns `None` - meaning "fall back to full retokenization" -
+/// if `edit_range` overlaps a punctuation token, since the edit may itself
+/// be changing that boundary, or if no token overlaps it at all (e.g. an
+/// empty document).
+fn affected_window(prev_tokens: &[Token], edit_range: &std::ops::Range<usize>) -> Option<(usize, usize)> {
+    let mut start_idx = None;
+    let mut end_idx = None;
+
+    for (i, token) in prev_tokens.iter().enumerate() {
+        let token_end = token.start + token.len;
+        let overlaps = token.start <= edit_range.end && edit_range.start <= token_end;
+        if !overlaps {
+            continue;
+        }
+        if token.chunk_type == ChunkType::Punct {
+            return None;
+        }
+        start_idx.get_or_insert(i);
+        end_idx = Some(i + 1);
+    }
+
+    let (mut start_idx, mut end_idx) = (start_idx?, end_idx?);
+
+    while start_idx > 0 && prev_tokens[start_idx - 1].chunk_type != ChunkType::Punct {
+        start_idx -= 1;
+    }
+    while end_idx < prev_tokens.len() && prev_tokens[end_idx].chunk_type != ChunkType::Punct {
+        end_idx += 1;
+    }
+
+    Some((start_idx, end_idx))
+}
+
 /// A simple tokenizer that doesn't use a dictionary (just syllabifies)
 pub struct SimpleTokenizer;
 
@@ -323,7 +755,7 @@ impl SimpleTokenizer {
                     chunk.chunk_type,
                 );
                 if let Some(syl) = chunk.syl {
-                    token.syls = vec![syl];
+                    token.syls = vec![syl.into_owned()];
                 }
                 token
             })
@@ -414,21 +846,45 @@ mod tests {
 
         // NFC form
         let tokens_nfc = tokenizer.tokenize("བཀྲ་ཤིས།");
-        
+
         // The tokenizer should handle both forms
         assert!(!tokens_nfc.is_empty());
     }
 
+    #[test]
+    fn test_normalize_tibetan_composes_nfd_to_nfc() {
+        // "é" as "e" + combining acute accent (U+0301) vs. its precomposed
+        // form (U+00E9) - a minimal, well-known NFD/NFC mismatch.
+        let composed = "bde\u{00E9}";
+        let decomposed = "bde\u{0065}\u{0301}";
+        assert_ne!(decomposed, composed);
+
+        assert_eq!(normalize_tibetan(decomposed), composed);
+    }
+
+    #[test]
+    fn test_tokenize_with_full_options_normalize_false_skips_normalization() {
+        let mut builder = TrieBuilder::new();
+        builder.load_tsv("bdé\tNOUN\t\t\t1000");
+        let tokenizer = Tokenizer::new(builder.build());
+
+        // The trie's key is NFC, so looking up the un-normalized NFD form
+        // with normalization disabled should fail to match.
+        let decomposed = "bde\u{0301}";
+        let tokens = tokenizer.tokenize_with_full_options(decomposed, true, false, false, false);
+        assert!(tokens.iter().all(|t| t.pos.is_none()));
+    }
+
     #[test]
     fn test_spaces_as_punct() {
         let trie = make_test_trie();
         let tokenizer = Tokenizer::new(trie);
 
         // Without spaces_as_punct, spaces are part of tokens
-        let tokens_normal = tokenizer.tokenize_with_full_options("བཀྲ་ཤིས་ བདེ་ལེགས།", true, false);
+        let tokens_normal = tokenizer.tokenize_with_full_options("བཀྲ་ཤིས་ བདེ་ལེགས།", true, false, false, true);
         
         // With spaces_as_punct, spaces become separate punctuation tokens
-        let tokens_space = tokenizer.tokenize_with_full_options("བཀྲ་ཤིས་ བདེ་ལེགས།", true, true);
+        let tokens_space = tokenizer.tokenize_with_full_options("བཀྲ་ཤིས་ བདེ་ལེགས།", true, true, false, true);
         
         // Should have more tokens when spaces are separate
         assert!(tokens_space.len() >= tokens_normal.len());
@@ -440,17 +896,237 @@ mod tests {
         assert!(!space_tokens.is_empty(), "Should have space as punctuation token");
     }
 
+    #[test]
+    fn test_max_prob_prefers_high_frequency_split() {
+        // Two shorter high-frequency words should win over a much rarer
+        // longer entry that would be chosen by greedy longest-match.
+        let tsv = r#"བཀྲ་ཤིས	NOUN			5000
+བདེ་ལེགས	NOUN			5000
+བཀྲ་ཤིས་བདེ་ལེགས	NOUN			1"#;
+
+        let mut builder = TrieBuilder::new();
+        builder.load_tsv(tsv);
+        let trie = builder.build();
+        let tokenizer = Tokenizer::new(trie);
+
+        let tokens = tokenizer.tokenize_max_prob("བཀྲ་ཤིས་བདེ་ལེགས།");
+
+        // Should split into the two high-frequency words, not the rare
+        // long entry, plus the trailing punctuation.
+        assert_eq!(tokens.len(), 3);
+        assert_eq!(tokens[0].syls, vec!["བཀྲ", "ཤིས"]);
+        assert_eq!(tokens[1].syls, vec!["བདེ", "ལེགས"]);
+        assert_eq!(tokens[2].chunk_type, ChunkType::Punct);
+    }
+
+    #[test]
+    fn test_max_prob_unknown_word() {
+        let trie = make_test_trie();
+        let tokenizer = Tokenizer::new(trie);
+
+        let tokens = tokenizer.tokenize_max_prob("ཀཀ་");
+
+        assert_eq!(tokens.len(), 1);
+        assert_eq!(tokens[0].pos, Some("NO_POS".to_string()));
+    }
+
+    #[test]
+    fn test_max_prob_matches_longest_match_on_unambiguous_text() {
+        let trie = make_test_trie();
+        let tokenizer = Tokenizer::new(trie);
+
+        let greedy = tokenizer.tokenize("བདེ་ལེགས།");
+        let max_prob = tokenizer.tokenize_max_prob("བདེ་ལེགས།");
+
+        assert_eq!(greedy.len(), max_prob.len());
+        assert_eq!(greedy[0].syls, max_prob[0].syls);
+    }
+
+    #[test]
+    fn test_longest_match_preference() {
+        // Greedy `tokenize` always keeps the longest dictionary match,
+        // regardless of frequency - the opposite of `tokenize_max_prob`.
+        let tsv = r#"བཀྲ་ཤིས	NOUN			5000
+བདེ་ལེགས	NOUN			5000
+བཀྲ་ཤིས་བདེ་ལེགས	NOUN			1"#;
+
+        let mut builder = TrieBuilder::new();
+        builder.load_tsv(tsv);
+        let trie = builder.build();
+        let tokenizer = Tokenizer::new(trie);
+
+        let tokens = tokenizer.tokenize("བཀྲ་ཤིས་བདེ་ལེགས།");
+
+        assert_eq!(tokens.len(), 2);
+        assert_eq!(tokens[0].syls, vec!["བཀྲ", "ཤིས", "བདེ", "ལེགས"]);
+        assert_eq!(tokens[1].chunk_type, ChunkType::Punct);
+    }
+
+    #[test]
+    fn test_backtracking_match() {
+        // If the trie walk runs past the longest match into a dead end,
+        // greedy tokenization backtracks to the last valid match instead of
+        // emitting no token for the run.
+        // The walk reaches བཀྲ་ཤིས་བདེ (a prefix of the longer entry, not a
+        // word) before the shad stops it.
+        let tsv = "བཀྲ་ཤིས\tNOUN\t\t\t1000\nབཀྲ་ཤིས་བདེ་ལེགས\tNOUN\t\t\t10";
+
+        let mut builder = TrieBuilder::new();
+        builder.load_tsv(tsv);
+        let trie = builder.build();
+        let tokenizer = Tokenizer::new(trie);
+
+        let tokens = tokenizer.tokenize("བཀྲ་ཤིས་བདེ།");
+
+        assert_eq!(tokens[0].syls, vec!["བཀྲ", "ཤིས"]);
+    }
+
+    #[test]
+    fn test_tokenize_with_suggestions_populates_unknown_tokens() {
+        let tsv = "ཀུན\tNOUN\t\t\t1000";
+        let mut builder = TrieBuilder::new();
+        builder.load_tsv(tsv);
+        let trie = builder.build();
+        let tokenizer = Tokenizer::new(trie);
+
+        // "ཁུན" (unknown) is one whole-syllable substitution away from "ཀུན".
+        let tokens = tokenizer.tokenize_with_suggestions("ཁུན་", 2);
+
+        assert_eq!(tokens.len(), 1);
+        assert_eq!(tokens[0].pos, Some("NO_POS".to_string()));
+        assert!(!tokens[0].suggestions.is_empty());
+        assert_eq!(tokens[0].suggestions[0].syls, vec!["ཀུན"]);
+    }
+
+    #[test]
+    fn test_suggest_looks_up_a_single_token_directly() {
+        let tsv = "ཀུན\tNOUN\t\t\t1000";
+        let mut builder = TrieBuilder::new();
+        builder.load_tsv(tsv);
+        let trie = builder.build();
+        let tokenizer = Tokenizer::new(trie);
+
+        let tokens = tokenizer.tokenize("ཁུན་");
+        let suggestions = tokenizer.suggest(&tokens[0], 2);
+
+        assert_eq!(suggestions[0].syls, vec!["ཀུན"]);
+    }
+
     #[test]
     fn test_spaces_as_punct_with_newline() {
         let trie = make_test_trie();
         let tokenizer = Tokenizer::new(trie);
 
-        let tokens = tokenizer.tokenize_with_full_options("བཀྲ་ཤིས་ \nབདེ་ལེགས།", true, true);
-        
+        let tokens = tokenizer.tokenize_with_full_options("བཀྲ་ཤིས་ \nབདེ་ལེགས།", true, true, false, true);
+
         // Should have a space+newline token
         let space_tokens: Vec<_> = tokens.iter()
             .filter(|t| t.text.contains('\n') && t.chunk_type == ChunkType::Punct)
             .collect();
         assert!(!space_tokens.is_empty(), "Should have space+newline as punctuation token");
     }
+
+    #[test]
+    fn test_preserve_source_offsets_maps_back_to_original_text() {
+        let trie = make_test_trie();
+        let tokenizer = Tokenizer::new(trie);
+        let text = "བཀྲ་ཤིས་ བདེ་ལེགས།";
+
+        let tokens = tokenizer.tokenize_with_full_options(text, true, false, true, true);
+
+        for token in &tokens {
+            assert_eq!(
+                &text[token.start..token.start + token.len],
+                token.text,
+                "token offsets should slice back to the token's own text in the original string"
+            );
+        }
+    }
+
+    #[test]
+    fn test_tokenize_into_reuses_buffer() {
+        let trie = make_test_trie();
+        let tokenizer = Tokenizer::new(trie);
+        let mut buf = vec![Token::default(); 3];
+
+        tokenizer.tokenize_into("བཀྲ་ཤིས་བདེ་ལེགས", &mut buf);
+        let expected = tokenizer.tokenize("བཀྲ་ཤིས་བདེ་ལེགས");
+
+        assert_eq!(buf.len(), expected.len());
+        for (actual, expected) in buf.iter().zip(expected.iter()) {
+            assert_eq!(actual.text, expected.text);
+            assert_eq!(actual.start, expected.start);
+            assert_eq!(actual.len, expected.len);
+        }
+    }
+
+    #[test]
+    fn test_reparse_matches_full_tokenize_for_edit_within_a_word() {
+        let trie = make_test_trie();
+        let tokenizer = Tokenizer::new(trie);
+        let text = "བཀྲ་ཤིས། བདེ་ལེགས";
+
+        let prev_tokens = tokenizer.tokenize(text);
+        let edit_range = text.len()..text.len();
+        let new_substring = "་ཀ";
+
+        let reparsed = tokenizer.reparse(&prev_tokens, text, edit_range, new_substring);
+
+        let mut new_text = text.to_string();
+        new_text.push_str(new_substring);
+        let full = tokenizer.tokenize(&new_text);
+
+        assert_eq!(reparsed.len(), full.len());
+        for (actual, expected) in reparsed.iter().zip(full.iter()) {
+            assert_eq!(actual.text, expected.text);
+            assert_eq!(actual.start, expected.start);
+            assert_eq!(actual.len, expected.len);
+        }
+    }
+
+    #[test]
+    fn test_reparse_falls_back_when_edit_overlaps_punctuation() {
+        let trie = make_test_trie();
+        let tokenizer = Tokenizer::new(trie);
+        let text = "བཀྲ་ཤིས། བདེ་ལེགས";
+
+        let prev_tokens = tokenizer.tokenize(text);
+        let punct_token =
+            prev_tokens.iter().find(|t| t.chunk_type == ChunkType::Punct).expect("expected a punctuation token");
+        let edit_range = punct_token.start..punct_token.start + punct_token.len;
+
+        let mut new_text = text.to_string();
+        new_text.replace_range(edit_range.clone(), "");
+        let full = tokenizer.tokenize(&new_text);
+
+        let reparsed = tokenizer.reparse(&prev_tokens, text, edit_range, "");
+
+        assert_eq!(reparsed.len(), full.len());
+        for (actual, expected) in reparsed.iter().zip(full.iter()) {
+            assert_eq!(actual.text, expected.text);
+            assert_eq!(actual.start, expected.start);
+        }
+    }
+
+    #[test]
+    fn test_token_positions() {
+        let trie = make_test_trie();
+        let tokenizer = Tokenizer::new(trie);
+        let text = "བཀྲ་ཤིས། བདེ་ལེགས";
+
+        let prev_tokens = tokenizer.tokenize(text);
+        let edit_range = text.len()..text.len();
+        let reparsed = tokenizer.reparse(&prev_tokens, text, edit_range, "་ཀ");
+
+        let mut new_text = text.to_string();
+        new_text.push_str("་ཀ");
+
+        for token in &reparsed {
+            assert_eq!(
+                &new_text[token.start..token.start + token.len],
+                token.text,
+                "token offsets should slice back to the token's own text after an incremental reparse"
+            );
+        }
+    }
 }
@@ -4,17 +4,30 @@
 //! longest-match lookups during tokenization.
 //!
 //! ## Auto-Inflection
-//! 
+//!
 //! When loading words from TSV files, the `TrieBuilder` can automatically generate
 //! all affixed forms of each word. This is essential for Tibetan NLP since Tibetan
 //! has productive affixation (particles like འི, ས, ར, etc. attach to words).
+//!
+//! ## Structural Sharing and [`TrieOverlay`]
+//!
+//! `TrieNode` children are shared behind `Arc`, so cloning a `Trie`
+//! (e.g. via [`Trie::snapshot`]) only clones the root node itself; any
+//! subsequent mutation on either copy clones just the path it touches via
+//! `Arc::make_mut`, leaving every other node shared. [`TrieOverlay`] builds
+//! on this to let a large base dictionary be loaded once and shared by
+//! many cheap, independently-writable layers - a user dictionary, say -
+//! without ever cloning or mutating the base.
 
 use crate::syllable::{AffixData, SylComponents};
-use crate::token::Sense;
+use crate::token::{Sense, Suggestion};
+use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
+use std::hash::Hash;
+use std::sync::Arc;
 
 /// Data associated with a word in the Trie
-#[derive(Debug, Clone, Default)]
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub struct WordData {
     /// Part-of-speech tag
     pub pos: Option<String>,
@@ -31,7 +44,7 @@ pub struct WordData {
 }
 
 /// Information about how a word can be affixed
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub struct AffixInfo {
     /// Length of the affix in characters
     pub len: usize,
@@ -41,18 +54,45 @@ pub struct AffixInfo {
     pub aa: bool,
 }
 
-/// A node in the Trie
-#[derive(Debug, Clone, Default)]
-pub struct TrieNode {
-    /// Children nodes, keyed by syllable
-    pub children: HashMap<String, TrieNode>,
+/// The comparison granularity used by [`Trie::fuzzy_search_with_unit`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MatchUnit {
+    /// Compare whole syllables against each other (the default).
+    Syllable,
+    /// Flatten both the query and each trie syllable into individual
+    /// characters, so a single-character slip inside one syllable costs
+    /// one edit instead of the whole syllable.
+    Character,
+}
+
+/// A node in the Trie, generic over the symbol type `K` stored at each
+/// level (a syllable `String` by default; see [`Trie`]).
+///
+/// Children are stored behind `Arc` so that cloning a node is O(1) in the
+/// size of its subtree - `Trie::clone`, `Trie::snapshot` and the
+/// [`TrieOverlay`] layer stack all rely on this to share untouched nodes
+/// instead of deep-copying them.
+#[derive(Debug, Clone)]
+pub struct TrieNode<K = String> {
+    /// Children nodes, keyed by symbol
+    pub children: HashMap<K, Arc<TrieNode<K>>>,
     /// Whether this node marks the end of a valid word
     pub is_leaf: bool,
     /// Data associated with this word (if is_leaf is true)
     pub data: Option<WordData>,
 }
 
-impl TrieNode {
+impl<K> Default for TrieNode<K> {
+    fn default() -> Self {
+        TrieNode {
+            children: HashMap::new(),
+            is_leaf: false,
+            data: None,
+        }
+    }
+}
+
+impl<K> TrieNode<K> {
     /// Create a new empty node
     pub fn new() -> Self {
         TrieNode::default()
@@ -69,16 +109,90 @@ impl TrieNode {
     }
 }
 
-/// A Trie for storing and looking up Tibetan words
-#[derive(Debug, Default, Clone)]
-pub struct Trie {
+/// Manual impl (rather than `#[derive(Serialize)]`) so children don't need
+/// serde's `rc` feature for `Arc<TrieNode<K>>`: we serialize the pointee
+/// through a plain reference instead.
+impl<K: Serialize + Eq + Hash> Serialize for TrieNode<K> {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let children: HashMap<&K, &TrieNode<K>> = self.children.iter().map(|(k, v)| (k, v.as_ref())).collect();
+        let mut state = serializer.serialize_struct("TrieNode", 3)?;
+        state.serialize_field("children", &children)?;
+        state.serialize_field("is_leaf", &self.is_leaf)?;
+        state.serialize_field("data", &self.data)?;
+        state.end()
+    }
+}
+
+impl<'de, K: Deserialize<'de> + Eq + Hash> Deserialize<'de> for TrieNode<K> {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        #[derive(Deserialize)]
+        struct Raw<K: Eq + Hash> {
+            children: HashMap<K, TrieNode<K>>,
+            is_leaf: bool,
+            data: Option<WordData>,
+        }
+
+        let raw = Raw::deserialize(deserializer)?;
+        Ok(TrieNode {
+            children: raw.children.into_iter().map(|(k, v)| (k, Arc::new(v))).collect(),
+            is_leaf: raw.is_leaf,
+            data: raw.data,
+        })
+    }
+}
+
+/// A Trie for storing and looking up words as sequences of symbols of type
+/// `K`. `K` defaults to `String` - a syllable trie split on the tsek `་` -
+/// so every existing caller keeps writing plain `Trie`/`TrieNode` and gets
+/// that behavior unchanged. Other symbol types (`char` for a character
+/// trie, `u8` for a byte trie, ...) can be used for experiments by naming
+/// `Trie<K>` explicitly; see [`Trie::add_syms`]/[`Trie::has_syms`] etc. for
+/// the generic entry points, and [`Trie::add`]/[`Trie::has_word`]/... for
+/// the syllable-specific convenience methods current callers already use.
+#[derive(Debug, Clone)]
+pub struct Trie<K = String> {
     /// The root node
-    root: TrieNode,
+    root: Arc<TrieNode<K>>,
     /// Number of words in the trie
     word_count: usize,
 }
 
-impl Trie {
+impl<K> Default for Trie<K> {
+    fn default() -> Self {
+        Trie {
+            root: Arc::new(TrieNode::default()),
+            word_count: 0,
+        }
+    }
+}
+
+/// Manual impl for the same reason as [`TrieNode`]'s: `root` is an `Arc`,
+/// serialized here through a plain reference.
+impl<K: Serialize + Eq + Hash> Serialize for Trie<K> {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("Trie", 2)?;
+        state.serialize_field("root", self.root.as_ref())?;
+        state.serialize_field("word_count", &self.word_count)?;
+        state.end()
+    }
+}
+
+impl<'de, K: Deserialize<'de> + Eq + Hash> Deserialize<'de> for Trie<K> {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        #[derive(Deserialize)]
+        struct Raw<K: Eq + Hash> {
+            root: TrieNode<K>,
+            word_count: usize,
+        }
+
+        let raw = Raw::deserialize(deserializer)?;
+        Ok(Trie { root: Arc::new(raw.root), word_count: raw.word_count })
+    }
+}
+
+impl<K: Eq + Hash + Clone> Trie<K> {
     /// Create a new empty Trie
     pub fn new() -> Self {
         Trie::default()
@@ -94,15 +208,108 @@ impl Trie {
         self.word_count == 0
     }
 
+    /// Get the root node, for backends that compile this trie into another
+    /// representation (e.g. [`crate::double_array::DoubleArrayTrie`]).
+    pub fn root(&self) -> &TrieNode<K> {
+        self.root.as_ref()
+    }
+
+    /// Take a cheap point-in-time copy of this trie. Because [`TrieNode`]
+    /// children are shared behind `Arc`, this is O(1) regardless of
+    /// how many words the trie holds: the snapshot shares every node with
+    /// `self` until one of the two is mutated, at which point only the
+    /// path touched by that mutation is copied (see [`TrieOverlay`] for a
+    /// layered, stacking version of this).
+    pub fn snapshot(&self) -> Self
+    where
+        K: Clone,
+    {
+        self.clone()
+    }
+
+    /// Add a word, given as any iterator of symbols, to the trie.
+    ///
+    /// Clones only the nodes along `syms`'s path (via `Arc::make_mut`), so
+    /// any other `Trie` still sharing the rest of this tree (e.g. from
+    /// [`Trie::snapshot`]) is unaffected.
+    pub fn add_syms<I>(&mut self, syms: I, data: Option<WordData>)
+    where
+        I: IntoIterator<Item = K>,
+    {
+        let mut current = Arc::make_mut(&mut self.root);
+
+        for sym in syms {
+            let child = current.children.entry(sym).or_insert_with(|| Arc::new(TrieNode::new()));
+            current = Arc::make_mut(child);
+        }
+
+        if !current.is_leaf {
+            self.word_count += 1;
+        }
+        current.is_leaf = true;
+
+        if let Some(d) = data {
+            current.data = Some(d);
+        }
+    }
+
+    /// Walk the trie by one symbol, returning the next node if it exists
+    pub fn walk_sym<'a>(&'a self, sym: &K, current: Option<&'a TrieNode<K>>) -> Option<&'a TrieNode<K>> {
+        let node = current.unwrap_or_else(|| self.root.as_ref());
+        node.children.get(sym).map(|child| child.as_ref())
+    }
+
+    /// Check if a word (given as any iterator of symbols) exists in the trie
+    pub fn has_syms<I>(&self, syms: I) -> bool
+    where
+        I: IntoIterator<Item = K>,
+    {
+        let mut current = self.root.as_ref();
+
+        for sym in syms {
+            match current.children.get(&sym) {
+                Some(node) => current = node.as_ref(),
+                None => return false,
+            }
+        }
+
+        current.is_leaf
+    }
+
+    /// Get the data for a word (given as any iterator of symbols) if it exists
+    pub fn get_data_syms<I>(&self, syms: I) -> Option<&WordData>
+    where
+        I: IntoIterator<Item = K>,
+    {
+        let mut current = self.root.as_ref();
+
+        for sym in syms {
+            match current.children.get(&sym) {
+                Some(node) => current = node.as_ref(),
+                None => return None,
+            }
+        }
+
+        if current.is_leaf {
+            current.data.as_ref()
+        } else {
+            None
+        }
+    }
+}
+
+impl Trie<String> {
     /// Add a word (as a slice of syllables) to the trie
     pub fn add(&mut self, syls: &[&str], data: Option<WordData>) {
-        let mut current = &mut self.root;
+        let mut current = Arc::make_mut(&mut self.root);
 
         for syl in syls {
-            current = current
-                .children
-                .entry(syl.to_string())
-                .or_insert_with(TrieNode::new);
+            current = Arc::make_mut(
+                current
+                    .children
+                    .entry(syl.to_string())
+                    .or_insert_with(|| Arc::new(TrieNode::new())),
+            );
         }
 
         if !current.is_leaf {
@@ -139,13 +346,15 @@ impl Trie {
             return None;
         }
 
-        let mut current = &mut self.root;
+        let mut current = Arc::make_mut(&mut self.root);
 
         for syl in &syls {
-            current = current
-                .children
-                .entry(syl.to_string())
-                .or_insert_with(TrieNode::new);
+            current = Arc::make_mut(
+                current
+                    .children
+                    .entry(syl.to_string())
+                    .or_insert_with(|| Arc::new(TrieNode::new())),
+            );
         }
 
         if !current.is_leaf {
@@ -171,13 +380,15 @@ impl Trie {
             return;
         }
 
-        let mut current = &mut self.root;
+        let mut current = Arc::make_mut(&mut self.root);
 
         for syl in &syls {
-            current = current
-                .children
-                .entry(syl.to_string())
-                .or_insert_with(TrieNode::new);
+            current = Arc::make_mut(
+                current
+                    .children
+                    .entry(syl.to_string())
+                    .or_insert_with(|| Arc::new(TrieNode::new())),
+            );
         }
 
         if !current.is_leaf {
@@ -205,19 +416,285 @@ impl Trie {
         }
     }
 
+    /// Compile this trie into a compact double-array representation and
+    /// write it to `path`, for loading large dictionaries via
+    /// [`Trie::load_mmap`] (which decodes the whole file into owned arrays).
+    #[cfg(feature = "mmap")]
+    pub fn save(&self, path: impl AsRef<std::path::Path>) -> Result<(), crate::double_array::DoubleArrayError> {
+        crate::double_array::DoubleArrayTrie::from_trie(self).save(path)
+    }
+
+    /// Memory-map a double-array trie previously written by [`Trie::save`].
+    ///
+    /// The returned [`crate::double_array::DoubleArrayTrie`] implements the
+    /// same `walk(syl, current) -> Option<State>` lookup semantics as
+    /// `Trie::walk`, so it is a drop-in read-only backend for large
+    /// dictionaries loaded at startup.
+    #[cfg(feature = "mmap")]
+    pub fn load_mmap(
+        path: impl AsRef<std::path::Path>,
+    ) -> Result<crate::double_array::DoubleArrayTrie, crate::double_array::DoubleArrayError> {
+        crate::double_array::DoubleArrayTrie::load_mmap(path)
+    }
+
+    /// Compile an Aho-Corasick style automaton from this trie, for
+    /// streaming multi-match tokenization via [`crate::automaton::StreamMatcher`]
+    /// that never has to restart from the root on a syllable mismatch.
+    pub fn build_automaton(&self) -> crate::automaton::Automaton {
+        crate::automaton::Automaton::from_trie(self)
+    }
+
+    /// Minimize this trie into an equivalent [`crate::dawg::Dawg`] by
+    /// hash-consing identical suffix subtrees bottom-up, collapsing the
+    /// shared tails that a fully-expanded affixed dictionary duplicates
+    /// per stem.
+    pub fn minimize(&self) -> crate::dawg::Dawg {
+        crate::dawg::Dawg::from_trie(self)
+    }
+
+    /// Minimize this trie and serialize the result to a compact binary
+    /// blob, for shipping a precompiled dictionary instead of re-parsing
+    /// a TSV on every startup.
+    #[cfg(feature = "mmap")]
+    pub fn serialize(&self) -> Result<Vec<u8>, crate::double_array::DoubleArrayError> {
+        self.minimize().serialize()
+    }
+
+    /// Deserialize a minimized DAWG previously produced by [`Trie::serialize`].
+    #[cfg(feature = "mmap")]
+    pub fn deserialize(bytes: &[u8]) -> Result<crate::dawg::Dawg, crate::double_array::DoubleArrayError> {
+        crate::dawg::Dawg::deserialize(bytes)
+    }
+
+    /// Write this trie itself (not compiled into another backend) to
+    /// `path` via bincode, so a later [`Trie::load`] can skip `TrieBuilder`
+    /// and TSV parsing entirely - the precompiled-dictionary cache used by
+    /// [`crate::dialect_pack::Config::build_trie`].
+    #[cfg(feature = "mmap")]
+    pub fn save_cache(&self, path: impl AsRef<std::path::Path>) -> Result<(), crate::double_array::DoubleArrayError> {
+        let bytes = bincode::serialize(self).map_err(|e| crate::double_array::DoubleArrayError::Serialize(e.to_string()))?;
+        std::fs::write(path, bytes).map_err(|e| crate::double_array::DoubleArrayError::Io(e.to_string()))
+    }
+
+    /// Load a trie previously written by [`Trie::save_cache`].
+    #[cfg(feature = "mmap")]
+    pub fn load(path: impl AsRef<std::path::Path>) -> Result<Self, crate::double_array::DoubleArrayError> {
+        let bytes = std::fs::read(path).map_err(|e| crate::double_array::DoubleArrayError::Io(e.to_string()))?;
+        bincode::deserialize(&bytes).map_err(|e| crate::double_array::DoubleArrayError::Serialize(e.to_string()))
+    }
+
     /// Walk the trie by one syllable, returning the next node if it exists
     pub fn walk<'a>(&'a self, syl: &str, current: Option<&'a TrieNode>) -> Option<&'a TrieNode> {
-        let node = current.unwrap_or(&self.root);
-        node.children.get(syl)
+        let node = current.unwrap_or_else(|| self.root.as_ref());
+        node.children.get(syl).map(|child| child.as_ref())
+    }
+
+    /// Find dictionary words within `max_dist` syllable-level
+    /// Damerau-Levenshtein distance of `syls` (insertions, deletions,
+    /// substitutions, and adjacent-syllable transpositions), for spelling
+    /// suggestions on unmatched input.
+    ///
+    /// This performs a DFS over the trie while maintaining a DP row over
+    /// `syls` (one row per trie depth, of length `syls.len() + 1`), pruning
+    /// any subtree whose row minimum already exceeds `max_dist`. Distance-0
+    /// matches are never returned, since those are already found by exact
+    /// longest-match lookup.
+    ///
+    /// Results are sorted by ascending edit distance, then by descending
+    /// frequency.
+    pub fn fuzzy_lookup(&self, syls: &[String], max_dist: usize) -> Vec<Suggestion> {
+        let initial_row: Vec<usize> = (0..=syls.len()).collect();
+        let mut current_word: Vec<String> = Vec::new();
+        let mut results: Vec<Suggestion> = Vec::new();
+
+        for (syl, child) in &self.root.children {
+            self.fuzzy_descend(syl, child, syls, None, &initial_row, None, max_dist, &mut current_word, &mut results);
+        }
+
+        results.sort_by(|a, b| a.distance.cmp(&b.distance).then_with(|| b.freq.cmp(&a.freq)));
+        results
+    }
+
+    /// Recursive DFS step for [`Trie::fuzzy_lookup`]: extends `prev_row` by
+    /// one trie syllable (`syl`/`node`), records a suggestion if `node` is a
+    /// match within tolerance, and recurses into children unless the whole
+    /// subtree is already out of range. `prev_syl`/`prev_prev_row` are the
+    /// parent's own syllable/row, needed to price a transposition against
+    /// the two trie syllables leading into this one.
+    #[allow(clippy::too_many_arguments)]
+    fn fuzzy_descend(
+        &self,
+        syl: &str,
+        node: &TrieNode,
+        syls: &[String],
+        prev_syl: Option<&str>,
+        prev_row: &[usize],
+        prev_prev_row: Option<&[usize]>,
+        max_dist: usize,
+        current_word: &mut Vec<String>,
+        results: &mut Vec<Suggestion>,
+    ) {
+        let mut row = vec![prev_row[0] + 1];
+        for (i, input_syl) in syls.iter().enumerate() {
+            let substitution_cost = if input_syl == syl { 0 } else { 1 };
+            let mut cost = (row[i] + 1).min(prev_row[i + 1] + 1).min(prev_row[i] + substitution_cost);
+
+            // Damerau transposition: the trie's previous syllable swapped
+            // with its current one matches the input's current two.
+            if i > 0 {
+                if let (Some(prev_syl), Some(prev_prev_row)) = (prev_syl, prev_prev_row) {
+                    if syl == syls[i - 1] && prev_syl == input_syl {
+                        cost = cost.min(prev_prev_row[i - 1] + 1);
+                    }
+                }
+            }
+
+            row.push(cost);
+        }
+
+        if *row.iter().min().unwrap() > max_dist {
+            return;
+        }
+
+        current_word.push(syl.to_string());
+
+        let distance = row[syls.len()];
+        if node.is_match() && distance > 0 && distance <= max_dist {
+            results.push(Suggestion {
+                syls: current_word.clone(),
+                distance,
+                freq: node.data.as_ref().and_then(|d| d.freq),
+            });
+        }
+
+        for (child_syl, child_node) in &node.children {
+            self.fuzzy_descend(child_syl, child_node, syls, Some(syl), &row, Some(prev_row), max_dist, current_word, results);
+        }
+
+        current_word.pop();
+    }
+
+    /// Find dictionary words within `max_dist` edit distance of `query`,
+    /// comparing at syllable granularity. See [`Trie::fuzzy_search_with_unit`]
+    /// to compare character-by-character instead.
+    ///
+    /// Unlike [`Trie::fuzzy_lookup`], exact (distance-0) matches are
+    /// included and the full [`WordData`] is returned rather than just its
+    /// frequency.
+    pub fn fuzzy_search<'a>(&'a self, query: &[&str], max_dist: usize) -> Vec<(Vec<String>, &'a WordData, usize)> {
+        self.fuzzy_search_with_unit(query, max_dist, MatchUnit::Syllable)
+    }
+
+    /// Like [`Trie::fuzzy_search`], but `unit` selects whether `query` (and
+    /// each trie syllable) is compared whole or, by flattening into
+    /// individual characters, character-by-character - so a single-character
+    /// slip inside a syllable costs one edit instead of an entire syllable's
+    /// worth of edit distance.
+    ///
+    /// This performs a DFS over the trie while maintaining a Levenshtein DP
+    /// row over the flattened query units (one row per unit of trie depth
+    /// consumed, of length `query_units.len() + 1`), pruning any subtree
+    /// whose row minimum already exceeds `max_dist`.
+    pub fn fuzzy_search_with_unit<'a>(
+        &'a self,
+        query: &[&str],
+        max_dist: usize,
+        unit: MatchUnit,
+    ) -> Vec<(Vec<String>, &'a WordData, usize)> {
+        let query_units: Vec<String> = match unit {
+            MatchUnit::Syllable => query.iter().map(|s| s.to_string()).collect(),
+            MatchUnit::Character => query.iter().flat_map(|s| s.chars()).map(|c| c.to_string()).collect(),
+        };
+
+        let initial_row: Vec<usize> = (0..=query_units.len()).collect();
+        let mut current_word: Vec<String> = Vec::new();
+        let mut results = Vec::new();
+
+        for (syl, child) in &self.root.children {
+            self.fuzzy_search_descend(syl, child, &query_units, unit, &initial_row, max_dist, &mut current_word, &mut results);
+        }
+
+        results.sort_by(|a, b| a.2.cmp(&b.2).then_with(|| b.1.freq.cmp(&a.1.freq)));
+        results
+    }
+
+    /// Recursive DFS step for [`Trie::fuzzy_search_with_unit`]: extends
+    /// `prev_row` by one trie syllable (`syl`/`node`) - one symbol at a time
+    /// in [`MatchUnit::Character`] mode - records a hit if `node` is a match
+    /// within tolerance, and recurses into children unless the whole subtree
+    /// is already out of range.
+    #[allow(clippy::too_many_arguments)]
+    fn fuzzy_search_descend<'a>(
+        &'a self,
+        syl: &str,
+        node: &'a TrieNode,
+        query_units: &[String],
+        unit: MatchUnit,
+        prev_row: &[usize],
+        max_dist: usize,
+        current_word: &mut Vec<String>,
+        results: &mut Vec<(Vec<String>, &'a WordData, usize)>,
+    ) {
+        let symbols: Vec<String> = match unit {
+            MatchUnit::Syllable => vec![syl.to_string()],
+            MatchUnit::Character => syl.chars().map(|c| c.to_string()).collect(),
+        };
+
+        let mut row = prev_row.to_vec();
+        for symbol in &symbols {
+            row = Self::extend_dp_row(&row, query_units, symbol);
+            if *row.iter().min().unwrap() > max_dist {
+                return;
+            }
+        }
+
+        current_word.push(syl.to_string());
+
+        let distance = row[query_units.len()];
+        if node.is_match() && distance <= max_dist {
+            if let Some(data) = node.data.as_ref() {
+                results.push((current_word.clone(), data, distance));
+            }
+        }
+
+        for (child_syl, child_node) in &node.children {
+            self.fuzzy_search_descend(child_syl, child_node, query_units, unit, &row, max_dist, current_word, results);
+        }
+
+        current_word.pop();
+    }
+
+    /// One step of the Levenshtein DP row recurrence: `new[0] = prev[0] + 1`
+    /// and `new[i] = min(prev[i] + 1, new[i-1] + 1, prev[i-1] + (query[i-1] != symbol))`.
+    fn extend_dp_row(prev_row: &[usize], query_units: &[String], symbol: &str) -> Vec<usize> {
+        let mut row = vec![prev_row[0] + 1];
+        for (i, unit) in query_units.iter().enumerate() {
+            let substitution_cost = if unit == symbol { 0 } else { 1 };
+            let cost = (row[i] + 1).min(prev_row[i + 1] + 1).min(prev_row[i] + substitution_cost);
+            row.push(cost);
+        }
+        row
+    }
+
+    /// Sum of the frequencies of every word stored in the trie (words with
+    /// no recorded frequency contribute 0). Used to normalize word
+    /// log-probabilities for frequency-weighted segmentation.
+    pub fn total_freq(&self) -> u64 {
+        fn sum_node(node: &TrieNode) -> u64 {
+            let own = node.data.as_ref().and_then(|d| d.freq).unwrap_or(0) as u64;
+            own + node.children.values().map(|child| sum_node(child)).sum::<u64>()
+        }
+
+        sum_node(&self.root)
     }
 
     /// Check if a word exists in the trie
     pub fn has_word(&self, syls: &[&str]) -> bool {
-        let mut current = &self.root;
+        let mut current = self.root.as_ref();
 
         for syl in syls {
             match current.children.get(*syl) {
-                Some(node) => current = node,
+                Some(node) => current = node.as_ref(),
                 None => return false,
             }
         }
@@ -227,11 +704,11 @@ impl Trie {
 
     /// Get the data for a word if it exists
     pub fn get_word_data(&self, syls: &[&str]) -> Option<&WordData> {
-        let mut current = &self.root;
+        let mut current = self.root.as_ref();
 
         for syl in syls {
             match current.children.get(*syl) {
-                Some(node) => current = node,
+                Some(node) => current = node.as_ref(),
                 None => return None,
             }
         }
@@ -245,11 +722,11 @@ impl Trie {
 
     /// Add data to an existing word
     pub fn add_data(&mut self, syls: &[&str], sense: Sense) -> bool {
-        let mut current = &mut self.root;
+        let mut current = Arc::make_mut(&mut self.root);
 
         for syl in syls {
             match current.children.get_mut(*syl) {
-                Some(node) => current = node,
+                Some(node) => current = Arc::make_mut(node),
                 None => return false,
             }
         }
@@ -271,11 +748,11 @@ impl Trie {
 
     /// Deactivate a word (make it not findable)
     pub fn deactivate(&mut self, syls: &[&str]) -> bool {
-        let mut current = &mut self.root;
+        let mut current = Arc::make_mut(&mut self.root);
 
         for syl in syls {
             match current.children.get_mut(*syl) {
-                Some(node) => current = node,
+                Some(node) => current = Arc::make_mut(node),
                 None => return false,
             }
         }
@@ -289,42 +766,208 @@ impl Trie {
         }
     }
 
-    /// Get a reference to the root node (for external traversal)
-    pub fn root(&self) -> &TrieNode {
-        &self.root
+    /// Deactivate a word from a string (will be split into syllables by tsek)
+    pub fn deactivate_word(&mut self, word: &str) -> bool {
+        let syls: Vec<&str> = word
+            .split('་')
+            .filter(|s| !s.is_empty())
+            .collect();
+
+        if syls.is_empty() {
+            return false;
+        }
+
+        self.deactivate(&syls)
+    }
+
+    /// Overwrite the `pos`/`lemma` of an already-present word without
+    /// otherwise changing the trie's structure or word count. Used to apply
+    /// adjustment overlays on top of a base dictionary; does nothing if the
+    /// word isn't present, and leaves a field unchanged when its override
+    /// is `None`.
+    pub fn adjust_word(&mut self, syls: &[&str], pos: Option<String>, lemma: Option<String>) -> bool {
+        let mut current = Arc::make_mut(&mut self.root);
+
+        for syl in syls {
+            match current.children.get_mut(*syl) {
+                Some(node) => current = Arc::make_mut(node),
+                None => return false,
+            }
+        }
+
+        if !current.is_leaf {
+            return false;
+        }
+
+        let data = current.data.get_or_insert_with(WordData::default);
+        if pos.is_some() {
+            data.pos = pos;
+        }
+        if lemma.is_some() {
+            data.lemma = lemma;
+        }
+
+        true
     }
 
     /// Merge another trie into this one
     pub fn merge(&mut self, other: &Trie) {
-        let added = Self::merge_nodes_recursive(&mut self.root, &other.root);
+        let added = Self::merge_nodes_recursive(Arc::make_mut(&mut self.root), &other.root);
         self.word_count += added;
     }
 
     fn merge_nodes_recursive(target: &mut TrieNode, source: &TrieNode) -> usize {
         let mut added = 0;
-        
+
         for (syl, source_child) in &source.children {
-            let target_child = target.children
-                .entry(syl.clone())
-                .or_insert_with(TrieNode::new);
-            
+            let target_child = Arc::make_mut(
+                target
+                    .children
+                    .entry(syl.clone())
+                    .or_insert_with(|| Arc::new(TrieNode::new())),
+            );
+
             if source_child.is_leaf && !target_child.is_leaf {
                 target_child.is_leaf = true;
                 added += 1;
             }
-            
+
             if source_child.is_leaf && source_child.data.is_some() {
                 target_child.data = source_child.data.clone();
             }
-            
+
             // Recursively merge children
             added += Self::merge_nodes_recursive(target_child, source_child);
         }
-        
+
         added
     }
 }
 
+/// Outcome of resolving a word through a [`TrieOverlay`]'s layer stack.
+enum Lookup<'a> {
+    /// Matched as a leaf in the layer it resolved at (the leaf may still
+    /// carry no [`WordData`], same as a plain [`Trie`] lookup).
+    Found(Option<&'a WordData>),
+    /// Tombstoned by a `deactivate` call in some layer, shadowing any
+    /// match further down the stack.
+    Removed,
+    NotFound,
+}
+
+/// One level of a [`TrieOverlay`] stack.
+#[derive(Clone)]
+enum Layer {
+    /// The shared base dictionary. Never mutated by the overlay.
+    Base(Trie),
+    /// A layer of additions and tombstoned removals stacked on `parent`.
+    Overlay {
+        parent: Arc<Layer>,
+        additions: Trie,
+        removed: Trie,
+    },
+}
+
+impl Layer {
+    fn resolve(&self, syls: &[&str]) -> Lookup<'_> {
+        match self {
+            Layer::Base(trie) => {
+                if trie.has_word(syls) {
+                    Lookup::Found(trie.get_word_data(syls))
+                } else {
+                    Lookup::NotFound
+                }
+            }
+            Layer::Overlay { parent, additions, removed } => {
+                if removed.has_word(syls) {
+                    Lookup::Removed
+                } else if additions.has_word(syls) {
+                    Lookup::Found(additions.get_word_data(syls))
+                } else {
+                    parent.resolve(syls)
+                }
+            }
+        }
+    }
+}
+
+/// A copy-on-write overlay on top of a shared base [`Trie`], for layering
+/// session-specific additions and deactivations (custom lemmas, domain
+/// terms, a user dictionary, ...) without mutating or cloning the base.
+///
+/// Internally this is a stack of layers, each an ordinary (small) `Trie`
+/// of its own additions plus a `Trie` of tombstoned removals. A lookup
+/// walks the stack from the newest layer down: a layer's own addition or
+/// removal shadows whatever the layers beneath it say, so `deactivate`
+/// works even for words the overlay itself never added - it just records
+/// a tombstone in the current layer rather than touching the parent.
+/// [`TrieOverlay::fork`] pushes a fresh, empty write layer on top of the
+/// current one in O(1), since every layer below it is shared via `Arc`
+/// rather than copied.
+pub struct TrieOverlay {
+    layer: Layer,
+}
+
+impl TrieOverlay {
+    /// Wrap `base` as the bottom of a new overlay stack, with one empty
+    /// writable layer on top of it ready for additions/removals.
+    pub fn new(base: Trie) -> Self {
+        TrieOverlay {
+            layer: Layer::Overlay {
+                parent: Arc::new(Layer::Base(base)),
+                additions: Trie::new(),
+                removed: Trie::new(),
+            },
+        }
+    }
+
+    /// Push a new, empty write layer on top of this one and return it.
+    /// `self` keeps working unchanged - the fork shares every layer
+    /// beneath it via `Arc`, so this is O(1) regardless of how large the
+    /// base dictionary or any existing layer is.
+    pub fn fork(&self) -> Self {
+        TrieOverlay {
+            layer: Layer::Overlay {
+                parent: Arc::new(self.layer.clone()),
+                additions: Trie::new(),
+                removed: Trie::new(),
+            },
+        }
+    }
+
+    /// Add a word to this overlay's top layer, shadowing any match for the
+    /// same word further down the stack. If this layer had previously
+    /// tombstoned the word, the tombstone is lifted.
+    pub fn add(&mut self, syls: &[&str], data: Option<WordData>) {
+        if let Layer::Overlay { additions, removed, .. } = &mut self.layer {
+            removed.deactivate(syls);
+            additions.add(syls, data);
+        }
+    }
+
+    /// Tombstone a word in this overlay's top layer, so it resolves as
+    /// absent regardless of what any layer beneath it contains.
+    pub fn deactivate(&mut self, syls: &[&str]) {
+        if let Layer::Overlay { additions, removed, .. } = &mut self.layer {
+            additions.deactivate(syls);
+            removed.add(syls, None);
+        }
+    }
+
+    /// Check whether a word resolves to a match anywhere in the layer stack.
+    pub fn has_word(&self, syls: &[&str]) -> bool {
+        matches!(self.layer.resolve(syls), Lookup::Found(_))
+    }
+
+    /// Get the data for a word, resolved through the layer stack.
+    pub fn get_word_data(&self, syls: &[&str]) -> Option<&WordData> {
+        match self.layer.resolve(syls) {
+            Lookup::Found(data) => data,
+            Lookup::Removed | Lookup::NotFound => None,
+        }
+    }
+}
+
 /// Builder for loading a Trie from TSV files
 /// 
 /// Supports auto-inflection: when `inflect` is enabled, all affixed forms
@@ -422,7 +1065,11 @@ impl TrieBuilder {
                 continue;
             }
 
-            let form = parts[0];
+            // Normalize to NFC so dictionary keys are in the same canonical
+            // form as tokenizer input (see `tokenizer::normalize_tibetan`),
+            // regardless of which form the TSV source was authored in.
+            let form = crate::tokenizer::normalize_tibetan(parts[0]);
+            let form = form.as_str();
             let pos = parts.get(1).and_then(|s| {
                 if s.is_empty() { None } else { Some(s.to_string()) }
             });
@@ -609,6 +1256,22 @@ mod tests {
         assert!(trie.has_word(&["བདེ", "ལེགས"]));
     }
 
+    #[test]
+    fn test_load_tsv_normalizes_nfd_forms_to_nfc() {
+        // "é" as "e" + combining acute accent (U+0301) vs. its precomposed
+        // form (U+00E9) - a minimal, well-known NFD/NFC mismatch.
+        let nfd_form = "bde\u{0301}";
+        let nfc_form = "bd\u{00E9}";
+        assert_ne!(nfd_form, nfc_form);
+
+        let tsv = format!("{}\tNOUN\t\t\t1000", nfd_form);
+        let mut builder = TrieBuilder::new();
+        builder.load_tsv(&tsv);
+        let trie = builder.build();
+
+        assert!(trie.has_word(&[nfc_form]));
+    }
+
     #[test]
     fn test_add_word_string() {
         let mut trie = Trie::new();
@@ -616,5 +1279,233 @@ mod tests {
 
         assert!(trie.has_word(&["བཀྲ", "ཤིས", "བདེ", "ལེགས"]));
     }
+
+    #[test]
+    fn test_fuzzy_lookup_finds_close_matches() {
+        let mut trie = Trie::new();
+        trie.add(&["བཀྲ", "ཤིས"], Some(WordData { freq: Some(1000), ..Default::default() }));
+        trie.add(&["བདེ", "ལེགས"], Some(WordData { freq: Some(500), ..Default::default() }));
+
+        // "ཤིས" substituted for "ལེགས" is one substitution away from བདེ་ལེགས,
+        // and "བདེ" for "བཀྲ" one away from བཀྲ་ཤིས, which ranks first by
+        // its higher frequency.
+        let input = vec!["བདེ".to_string(), "ཤིས".to_string()];
+        let suggestions = trie.fuzzy_lookup(&input, 1);
+
+        assert_eq!(suggestions.len(), 2);
+        assert_eq!(suggestions[0].syls, vec!["བཀྲ", "ཤིས"]);
+        assert_eq!(suggestions[1].syls, vec!["བདེ", "ལེགས"]);
+        assert_eq!(suggestions[1].distance, 1);
+    }
+
+    #[test]
+    fn test_fuzzy_lookup_excludes_exact_matches() {
+        let mut trie = Trie::new();
+        trie.add(&["བཀྲ", "ཤིས"], Some(WordData { freq: Some(1000), ..Default::default() }));
+
+        let input = vec!["བཀྲ".to_string(), "ཤིས".to_string()];
+        let suggestions = trie.fuzzy_lookup(&input, 2);
+
+        assert!(suggestions.is_empty());
+    }
+
+    #[test]
+    fn test_fuzzy_lookup_finds_transposed_syllables() {
+        let mut trie = Trie::new();
+        trie.add(&["བཀྲ", "ཤིས"], Some(WordData { freq: Some(1000), ..Default::default() }));
+
+        // The two syllables are swapped - one transposition away, not two
+        // substitutions.
+        let input = vec!["ཤིས".to_string(), "བཀྲ".to_string()];
+        let suggestions = trie.fuzzy_lookup(&input, 1);
+
+        assert_eq!(suggestions.len(), 1);
+        assert_eq!(suggestions[0].syls, vec!["བཀྲ", "ཤིས"]);
+        assert_eq!(suggestions[0].distance, 1);
+    }
+
+    #[test]
+    fn test_fuzzy_lookup_respects_max_dist() {
+        let mut trie = Trie::new();
+        trie.add(&["ཀུན", "བཟང"], Some(WordData { freq: Some(10), ..Default::default() }));
+
+        let input = vec!["ཁ".to_string()];
+        let suggestions = trie.fuzzy_lookup(&input, 1);
+
+        assert!(suggestions.is_empty());
+    }
+
+    #[test]
+    fn test_fuzzy_search_includes_exact_matches() {
+        let mut trie = Trie::new();
+        trie.add(&["བཀྲ", "ཤིས"], Some(WordData { freq: Some(1000), ..Default::default() }));
+
+        let hits = trie.fuzzy_search(&["བཀྲ", "ཤིས"], 0);
+
+        assert_eq!(hits.len(), 1);
+        assert_eq!(hits[0].0, vec!["བཀྲ".to_string(), "ཤིས".to_string()]);
+        assert_eq!(hits[0].2, 0);
+    }
+
+    #[test]
+    fn test_fuzzy_search_respects_max_dist() {
+        let mut trie = Trie::new();
+        trie.add(&["ཀུན", "བཟང"], Some(WordData { freq: Some(10), ..Default::default() }));
+
+        let hits = trie.fuzzy_search(&["ཁ"], 1);
+
+        assert!(hits.is_empty());
+    }
+
+    #[test]
+    fn test_fuzzy_search_character_unit_ignores_syllable_boundaries() {
+        let mut trie = Trie::new();
+        trie.add(&["བཀྲ", "ཤིས"], Some(WordData { freq: Some(1), ..Default::default() }));
+
+        // A single, un-tsek-segmented blob with the same characters as the
+        // two-syllable dictionary entry. At syllable granularity the query
+        // has one unit against a two-node trie path, so it never matches
+        // closely enough even with zero tolerance for character drift.
+        let query = ["བཀྲཤིས"];
+        let syllable_hits = trie.fuzzy_search_with_unit(&query, 0, MatchUnit::Syllable);
+        assert!(syllable_hits.is_empty(), "mis-chunked query shouldn't match at syllable granularity");
+
+        // Flattened to characters, the same text is identical to the
+        // dictionary entry's characters regardless of where the trie put its
+        // syllable boundary, so it matches exactly.
+        let char_hits = trie.fuzzy_search_with_unit(&query, 0, MatchUnit::Character);
+        assert_eq!(char_hits.len(), 1);
+        assert_eq!(char_hits[0].2, 0);
+    }
+
+    #[test]
+    fn test_total_freq() {
+        let mut trie = Trie::new();
+        trie.add(&["བཀྲ", "ཤིས"], Some(WordData { freq: Some(1000), ..Default::default() }));
+        trie.add(&["བདེ", "ལེགས"], Some(WordData { freq: Some(500), ..Default::default() }));
+        trie.add(&["ཀུན"], None);
+
+        assert_eq!(trie.total_freq(), 1500);
+    }
+
+    #[test]
+    fn test_generic_trie_over_chars() {
+        let mut trie: Trie<char> = Trie::new();
+        trie.add_syms("tashi".chars(), Some(WordData { freq: Some(1), ..Default::default() }));
+        trie.add_syms("ta".chars(), None);
+
+        assert!(trie.has_syms("tashi".chars()));
+        assert!(trie.has_syms("ta".chars()));
+        assert!(!trie.has_syms("tas".chars()));
+        assert_eq!(trie.get_data_syms("tashi".chars()).and_then(|d| d.freq), Some(1));
+
+        let n1 = trie.walk_sym(&'t', None);
+        assert!(n1.is_some());
+        let n2 = trie.walk_sym(&'a', n1);
+        assert!(n2.is_some() && n2.unwrap().is_match());
+    }
+
+    #[test]
+    fn test_snapshot_is_independent_of_later_mutation() {
+        let mut trie = Trie::new();
+        trie.add(&["བཀྲ", "ཤིས"], Some(WordData { freq: Some(1000), ..Default::default() }));
+
+        let snapshot = trie.snapshot();
+        trie.add(&["བདེ", "ལེགས"], None);
+
+        assert!(trie.has_word(&["བདེ", "ལེགས"]));
+        assert!(!snapshot.has_word(&["བདེ", "ལེགས"]));
+        assert!(snapshot.has_word(&["བཀྲ", "ཤིས"]));
+    }
+
+    #[test]
+    fn test_overlay_add_shadows_base_without_mutating_it() {
+        let mut base = Trie::new();
+        base.add(&["བཀྲ", "ཤིས"], Some(WordData { freq: Some(1000), ..Default::default() }));
+
+        let mut overlay = TrieOverlay::new(base.clone());
+        overlay.add(&["བདེ", "ལེགས"], Some(WordData { freq: Some(1), ..Default::default() }));
+
+        assert!(overlay.has_word(&["བཀྲ", "ཤིས"]));
+        assert!(overlay.has_word(&["བདེ", "ལེགས"]));
+        assert!(!base.has_word(&["བདེ", "ལེགས"]));
+    }
+
+    #[test]
+    fn test_overlay_deactivate_tombstones_base_entry() {
+        let mut base = Trie::new();
+        base.add(&["བཀྲ", "ཤིས"], None);
+
+        let mut overlay = TrieOverlay::new(base.clone());
+        assert!(overlay.has_word(&["བཀྲ", "ཤིས"]));
+
+        overlay.deactivate(&["བཀྲ", "ཤིས"]);
+        assert!(!overlay.has_word(&["བཀྲ", "ཤིས"]));
+
+        // The base itself is untouched - only the overlay layer shadows it.
+        assert!(base.has_word(&["བཀྲ", "ཤིས"]));
+    }
+
+    #[test]
+    fn test_overlay_fork_is_independent_sibling() {
+        let mut base = Trie::new();
+        base.add(&["ཀུན", "བཟང"], None);
+
+        let overlay = TrieOverlay::new(base);
+        let mut fork_a = overlay.fork();
+        let mut fork_b = overlay.fork();
+
+        fork_a.add(&["བདེ", "ལེགས"], None);
+        fork_b.deactivate(&["ཀུན", "བཟང"]);
+
+        assert!(fork_a.has_word(&["བདེ", "ལེགས"]));
+        assert!(fork_a.has_word(&["ཀུན", "བཟང"])); // still inherited from base
+        assert!(!fork_b.has_word(&["བདེ", "ལེགས"]));
+        assert!(!fork_b.has_word(&["ཀུན", "བཟང"])); // tombstoned in this fork only
+        assert!(overlay.has_word(&["ཀུན", "བཟང"])); // parent overlay unaffected by either fork
+    }
+
+    #[test]
+    fn test_overlay_readd_lifts_own_tombstone() {
+        let mut base = Trie::new();
+        base.add(&["ཀུན", "བཟང"], None);
+
+        let mut overlay = TrieOverlay::new(base);
+        overlay.deactivate(&["ཀུན", "བཟང"]);
+        assert!(!overlay.has_word(&["ཀུན", "བཟང"]));
+
+        overlay.add(&["ཀུན", "བཟང"], Some(WordData { freq: Some(5), ..Default::default() }));
+        assert!(overlay.has_word(&["ཀུན", "བཟང"]));
+        assert_eq!(overlay.get_word_data(&["ཀུན", "བཟང"]).and_then(|d| d.freq), Some(5));
+    }
+
+    #[test]
+    fn test_deactivate_word_string() {
+        let mut trie = Trie::new();
+        trie.add_word("བཀྲ་ཤིས", None);
+        assert!(trie.has_word(&["བཀྲ", "ཤིས"]));
+
+        assert!(trie.deactivate_word("བཀྲ་ཤིས"));
+        assert!(!trie.has_word(&["བཀྲ", "ཤིས"]));
+    }
+
+    #[test]
+    fn test_adjust_word_overwrites_pos_and_lemma_only() {
+        let mut trie = Trie::new();
+        trie.add(&["བཀྲ", "ཤིས"], Some(WordData { pos: Some("NOUN".into()), freq: Some(1000), ..Default::default() }));
+
+        assert!(trie.adjust_word(&["བཀྲ", "ཤིས"], Some("ADJ".into()), Some("བཀྲ་ཤིས".into())));
+
+        let data = trie.get_word_data(&["བཀྲ", "ཤིས"]).unwrap();
+        assert_eq!(data.pos.as_deref(), Some("ADJ"));
+        assert_eq!(data.lemma.as_deref(), Some("བཀྲ་ཤིས"));
+        assert_eq!(data.freq, Some(1000));
+    }
+
+    #[test]
+    fn test_adjust_word_absent_is_noop() {
+        let mut trie = Trie::new();
+        assert!(!trie.adjust_word(&["མེད", "པ"], Some("ADJ".into()), None));
+    }
 }
 
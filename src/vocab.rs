@@ -0,0 +1,312 @@
+//! Vocabulary encoding for feeding tokenized Tibetan text into ML models.
+//!
+//! [`VocabTokenizer`] sits on top of [`Token`]s produced by
+//! [`crate::Tokenizer`]/[`crate::SimpleTokenizer`] and maps each one to an
+//! integer id, following the offsets/attention-mask shape used by the
+//! `rust_tokenizers` crate: an [`Encoding`] carries `ids`, `offsets`,
+//! `special_tokens_mask`, and `attention_mask` in lockstep, ready to feed
+//! directly into a transformer model.
+
+use std::collections::HashMap;
+
+use crate::token::Token;
+
+/// Default id for a subword with no vocabulary entry.
+pub const DEFAULT_UNK_ID: u32 = 0;
+/// Default id used to pad a batch of encodings to a common length.
+pub const DEFAULT_PAD_ID: u32 = 1;
+
+/// The tsek (syllable separator) used to rejoin a [`Token::syls`] slice into
+/// the vocabulary key format, matching how syllables are joined elsewhere
+/// (e.g. [`crate::Trie::fuzzy_lookup`] suggestions).
+const SYL_SEP: &str = "་";
+
+/// The result of encoding a slice of [`Token`]s against a [`VocabTokenizer`]'s
+/// vocabulary: parallel vectors ready for ML model input.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Encoding {
+    /// Vocabulary index of every emitted subword
+    pub ids: Vec<u32>,
+    /// Byte `(start, end)` span into the original text for each id
+    pub offsets: Vec<(usize, usize)>,
+    /// 1 for a special (e.g. padding) position, 0 for real text
+    pub special_tokens_mask: Vec<u8>,
+    /// 1 for a real position to attend to, 0 for padding
+    pub attention_mask: Vec<u8>,
+}
+
+impl Encoding {
+    /// Number of ids in this encoding.
+    pub fn len(&self) -> usize {
+        self.ids.len()
+    }
+
+    /// Whether this encoding has no ids.
+    pub fn is_empty(&self) -> bool {
+        self.ids.is_empty()
+    }
+
+    /// Pad (or truncate) this encoding to exactly `target_len`, using
+    /// `pad_id` for any appended positions.
+    pub fn pad_to(&mut self, target_len: usize, pad_id: u32) {
+        if target_len > self.ids.len() {
+            let pad_count = target_len - self.ids.len();
+            self.ids.extend(std::iter::repeat_n(pad_id, pad_count));
+            self.offsets.extend(std::iter::repeat_n((0, 0), pad_count));
+            self.special_tokens_mask.extend(std::iter::repeat_n(1, pad_count));
+            self.attention_mask.extend(std::iter::repeat_n(0, pad_count));
+        } else {
+            self.ids.truncate(target_len);
+            self.offsets.truncate(target_len);
+            self.special_tokens_mask.truncate(target_len);
+            self.attention_mask.truncate(target_len);
+        }
+    }
+}
+
+/// Maps [`Token`]s to vocabulary ids for ML pipelines.
+///
+/// Each token is first looked up whole (its full text for non-syllable
+/// tokens, or its syllables rejoined with a tsek for syllable tokens). If
+/// that misses, syllable tokens fall back to a greedy longest-syllable-prefix
+/// search: the longest leading run of syllables with a vocabulary entry is
+/// emitted, the remainder is retried the same way, and any syllable that
+/// still can't be matched on its own is emitted as `unk_id`. This guarantees
+/// every token produces at least one id and every syllable is covered by
+/// exactly one id.
+pub struct VocabTokenizer {
+    vocab: HashMap<String, u32>,
+    unk_id: u32,
+    pad_id: u32,
+}
+
+impl VocabTokenizer {
+    /// Build a vocabulary from a `form\tid` TSV string, one entry per line.
+    /// Blank lines and `#` comments are skipped, mirroring
+    /// [`crate::TrieBuilder::load_tsv`]'s line format.
+    pub fn from_tsv(tsv_content: &str) -> Self {
+        let mut vocab = HashMap::new();
+
+        for line in tsv_content.lines() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            let mut fields = line.split('\t');
+            let Some(form) = fields.next() else { continue };
+            let Some(id) = fields.next().and_then(|s| s.trim().parse::<u32>().ok()) else { continue };
+
+            vocab.insert(crate::tokenizer::normalize_tibetan(form), id);
+        }
+
+        VocabTokenizer { vocab, unk_id: DEFAULT_UNK_ID, pad_id: DEFAULT_PAD_ID }
+    }
+
+    /// Use `id` for out-of-vocabulary subwords instead of [`DEFAULT_UNK_ID`].
+    pub fn with_unk_id(mut self, id: u32) -> Self {
+        self.unk_id = id;
+        self
+    }
+
+    /// Use `id` for padding positions instead of [`DEFAULT_PAD_ID`].
+    pub fn with_pad_id(mut self, id: u32) -> Self {
+        self.pad_id = id;
+        self
+    }
+
+    /// The id assigned to out-of-vocabulary subwords.
+    pub fn unk_id(&self) -> u32 {
+        self.unk_id
+    }
+
+    /// The id used for padding positions.
+    pub fn pad_id(&self) -> u32 {
+        self.pad_id
+    }
+
+    /// Number of entries in the vocabulary.
+    pub fn len(&self) -> usize {
+        self.vocab.len()
+    }
+
+    /// Whether the vocabulary has no entries.
+    pub fn is_empty(&self) -> bool {
+        self.vocab.is_empty()
+    }
+
+    /// Encode a slice of already-tokenized [`Token`]s into an [`Encoding`].
+    pub fn encode(&self, tokens: &[Token]) -> Encoding {
+        let mut encoding = Encoding::default();
+
+        for token in tokens {
+            if token.syls.is_empty() {
+                self.encode_whole_token(token, &mut encoding);
+            } else {
+                self.encode_syllables(token, &mut encoding);
+            }
+        }
+
+        encoding
+    }
+
+    /// Emit a single id for a non-syllable token (punctuation, numbers, ...),
+    /// looking it up by its raw text.
+    fn encode_whole_token(&self, token: &Token, encoding: &mut Encoding) {
+        let id = self.vocab.get(&token.text).copied().unwrap_or(self.unk_id);
+        self.push(encoding, id, token.start, token.start + token.len);
+    }
+
+    /// Greedy longest-syllable-prefix encoding of a syllable token, falling
+    /// back one syllable at a time until a match is found.
+    fn encode_syllables(&self, token: &Token, encoding: &mut Encoding) {
+        let spans = syllable_byte_spans(token);
+        let mut i = 0;
+
+        while i < token.syls.len() {
+            let mut matched = false;
+
+            for j in (i + 1..=token.syls.len()).rev() {
+                let key = token.syls[i..j].join(SYL_SEP);
+                if let Some(&id) = self.vocab.get(&key) {
+                    let (start, _) = spans[i];
+                    let (_, end) = spans[j - 1];
+                    self.push(encoding, id, start, end);
+                    i = j;
+                    matched = true;
+                    break;
+                }
+            }
+
+            if !matched {
+                let (start, end) = spans[i];
+                self.push(encoding, self.unk_id, start, end);
+                i += 1;
+            }
+        }
+    }
+
+    fn push(&self, encoding: &mut Encoding, id: u32, start: usize, end: usize) {
+        encoding.ids.push(id);
+        encoding.offsets.push((start, end));
+        encoding.special_tokens_mask.push(0);
+        encoding.attention_mask.push(1);
+    }
+}
+
+/// Compute each syllable's byte `(start, end)` span within `token`'s
+/// original-text range from the syllables' own lengths, counting one tsek
+/// between consecutive syllables from `token.start`. `token.text` itself is
+/// not read; where the source has extra tseks or spaces inside the token,
+/// the spans drift from the text.
+fn syllable_byte_spans(token: &Token) -> Vec<(usize, usize)> {
+    let mut spans = Vec::with_capacity(token.syls.len());
+    let mut offset = token.start;
+
+    for (i, syl) in token.syls.iter().enumerate() {
+        if i > 0 {
+            offset += SYL_SEP.len();
+        }
+        let end = offset + syl.len();
+        spans.push((offset, end));
+        offset = end;
+    }
+
+    spans
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::token::ChunkType;
+
+    fn make_vocab() -> VocabTokenizer {
+        VocabTokenizer::from_tsv(
+            "བཀྲ་ཤིས\t2\nབདེ\t3\nལེགས\t4\n# a comment\n\n",
+        )
+    }
+
+    fn syllable_token(text: &str, start: usize, syls: &[&str]) -> Token {
+        Token {
+            text: text.to_string(),
+            start,
+            len: text.len(),
+            chunk_type: ChunkType::Text,
+            syls: syls.iter().map(|s| s.to_string()).collect(),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_encode_whole_word_match() {
+        let vocab = make_vocab();
+        let token = syllable_token("བཀྲ་ཤིས", 0, &["བཀྲ", "ཤིས"]);
+
+        let encoding = vocab.encode(&[token]);
+
+        assert_eq!(encoding.ids, vec![2]);
+        assert_eq!(encoding.offsets, vec![(0, "བཀྲ་ཤིས".len())]);
+        assert_eq!(encoding.attention_mask, vec![1]);
+        assert_eq!(encoding.special_tokens_mask, vec![0]);
+    }
+
+    #[test]
+    fn test_encode_falls_back_to_longest_syllable_prefix() {
+        let vocab = make_vocab();
+        // Not in vocab as a whole word, but both syllables are individually.
+        let token = syllable_token("བདེ་ལེགས", 0, &["བདེ", "ལེགས"]);
+
+        let encoding = vocab.encode(&[token]);
+
+        assert_eq!(encoding.ids, vec![3, 4]);
+        assert_eq!(encoding.offsets.len(), 2);
+    }
+
+    #[test]
+    fn test_encode_unknown_syllable_uses_unk_id() {
+        let vocab = make_vocab();
+        let token = syllable_token("ཀཀ", 0, &["ཀཀ"]);
+
+        let encoding = vocab.encode(&[token]);
+
+        assert_eq!(encoding.ids, vec![DEFAULT_UNK_ID]);
+    }
+
+    #[test]
+    fn test_encode_non_syllable_token_looks_up_raw_text() {
+        let mut vocab = make_vocab();
+        vocab.vocab.insert("།".to_string(), 5);
+        let token = Token {
+            text: "།".to_string(),
+            start: 10,
+            len: "།".len(),
+            chunk_type: ChunkType::Punct,
+            ..Default::default()
+        };
+
+        let encoding = vocab.encode(&[token]);
+
+        assert_eq!(encoding.ids, vec![5]);
+        assert_eq!(encoding.offsets, vec![(10, 10 + "།".len())]);
+    }
+
+    #[test]
+    fn test_pad_to_extends_with_pad_id_and_masks() {
+        let mut encoding = Encoding { ids: vec![2], offsets: vec![(0, 3)], ..Default::default() };
+        encoding.special_tokens_mask.push(0);
+        encoding.attention_mask.push(1);
+
+        encoding.pad_to(3, DEFAULT_PAD_ID);
+
+        assert_eq!(encoding.ids, vec![2, DEFAULT_PAD_ID, DEFAULT_PAD_ID]);
+        assert_eq!(encoding.attention_mask, vec![1, 0, 0]);
+        assert_eq!(encoding.special_tokens_mask, vec![0, 1, 1]);
+    }
+
+    #[test]
+    fn test_with_unk_and_pad_id_overrides_defaults() {
+        let vocab = VocabTokenizer::from_tsv("").with_unk_id(99).with_pad_id(100);
+        assert_eq!(vocab.unk_id(), 99);
+        assert_eq!(vocab.pad_id(), 100);
+    }
+}
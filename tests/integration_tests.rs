@@ -75,7 +75,7 @@ fn test_chunks_basic() {
 
     // First chunks should be TEXT (syllables)
     assert_eq!(chunks[0].chunk_type, ChunkType::Text);
-    assert_eq!(chunks[0].syl, Some("བཀྲ".to_string()));
+    assert_eq!(chunks[0].syl.as_deref(), Some("བཀྲ"));
 
     // Last chunk should be PUNCT
     assert_eq!(chunks.last().unwrap().chunk_type, ChunkType::Punct);
@@ -484,7 +484,7 @@ fn test_spaces_as_punct() {
     let tokenizer = Tokenizer::new(trie);
     
     // With spaces_as_punct=true
-    let tokens = tokenizer.tokenize_with_full_options("བཀྲ་ཤིས་ བདེ་ལེགས།", true, true);
+    let tokens = tokenizer.tokenize_with_full_options("བཀྲ་ཤིས་ བདེ་ལེགས།", true, true, false, false);
     
     // Should have space as a separate punctuation token
     let space_tokens: Vec<_> = tokens.iter()
@@ -505,7 +505,7 @@ fn test_spaces_with_newline() {
     let tokenizer = Tokenizer::new(trie);
     
     // With spaces_as_punct=true and newline in text
-    let tokens = tokenizer.tokenize_with_full_options("བཀྲ་ཤིས་ \nབདེ་", true, true);
+    let tokens = tokenizer.tokenize_with_full_options("བཀྲ་ཤིས་ \nབདེ་", true, true, false, false);
     
     // Should have space+newline as punctuation
     let newline_tokens: Vec<_> = tokens.iter()